@@ -4,12 +4,24 @@
 #![allow(dead_code)]
 
 mod rc_list;
-use std::borrow::Cow;
+#[cfg(not(feature = "std"))]
+mod string;
 
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
 use compact_str::{CompactString, ToCompactString};
 use smallvec::SmallVec;
 
 use self::rc_list::List;
+#[cfg(not(feature = "std"))]
+use self::string::CompactString;
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+pub use self::string::ToCompactString;
 
 /// A validation error report.
 ///
@@ -18,42 +30,253 @@ use self::rc_list::List;
 /// A single field or list item may have any number of errors attached to it.
 ///
 /// It is possible to extract all errors for specific field using the [`select`][`crate::select`] macro.
+///
+/// A report is usually built by the derived implementation of [`Validate`][`crate::Validate`], but it may also be built by hand,
+/// e.g. in a manual implementation of [`Validate`][`crate::Validate`]. [`Report`], [`Path`], [`Error`], and the
+/// [`nested_path`][`crate::util::nested_path`] macro are the same building blocks used by the derive macro:
+///
+/// ```rust
+/// use garde::{Error, Path, Report, Validate};
+///
+/// struct User {
+///     name: String,
+///     emails: Vec<String>,
+/// }
+///
+/// impl Validate for User {
+///     type Context = ();
+///
+///     fn validate_into(
+///         &self,
+///         _: &Self::Context,
+///         mut parent: &mut dyn FnMut() -> Path,
+///         report: &mut Report,
+///     ) {
+///         if self.name.is_empty() {
+///             let mut path = garde::util::nested_path!(parent, "name");
+///             report.append(path(), Error::new("must not be empty"));
+///         }
+///
+///         let mut emails = garde::util::nested_path!(parent, "emails");
+///         for (index, email) in self.emails.iter().enumerate() {
+///             if !email.contains('@') {
+///                 let mut path = garde::util::nested_path!(emails, index);
+///                 report.append(path(), Error::new("not a valid email"));
+///             }
+///         }
+///     }
+/// }
+///
+/// let user = User {
+///     name: String::new(),
+///     emails: vec!["a@b.c".into(), "invalid".into()],
+/// };
+/// let report = user.validate().unwrap_err();
+/// assert_eq!(
+///     report.to_string(),
+///     "name: must not be empty\nemails[1]: not a valid email\n"
+/// );
+/// ```
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Report {
     errors: Vec<(Path, Error)>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    fail_fast: bool,
 }
 
 impl Report {
     /// Create an empty [`Report`].
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { errors: Vec::new() }
+        Self {
+            errors: Vec::new(),
+            fail_fast: false,
+        }
+    }
+
+    /// Create an empty [`Report`] which only records the first error appended to it.
+    ///
+    /// Validation stops as soon as this report contains an error, see [`Report::should_stop`].
+    pub fn fail_fast() -> Self {
+        Self {
+            errors: Vec::new(),
+            fail_fast: true,
+        }
     }
 
     /// Append an [`Error`] into this report at the given [`Path`].
     pub fn append(&mut self, path: Path, error: Error) {
+        if self.should_stop() {
+            return;
+        }
         self.errors.push((path, error));
     }
 
+    /// Append an [`Error`] into this report at the root, which is the empty [`Path`].
+    ///
+    /// This is meant for errors which apply to the whole value, rather than to any one field.
+    /// Root-level errors are displayed without a path, and are flattened under the empty JSON Pointer `""`.
+    ///
+    /// ```rust
+    /// # use garde::{Error, Path, Report};
+    /// let mut report = Report::new();
+    /// report.append_root(Error::new("at least one contact method is required"));
+    /// report.append(Path::new("email"), Error::new("not a valid email"));
+    ///
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "at least one contact method is required\nemail: not a valid email\n"
+    /// );
+    /// assert_eq!(report.flatten_json_pointer()[""], ["at least one contact method is required"]);
+    /// ```
+    pub fn append_root(&mut self, error: Error) {
+        self.append(Path::empty(), error)
+    }
+
+    /// Returns `true` if validation should not continue.
+    ///
+    /// This is only ever the case for a [`Report::fail_fast`] report which already contains an error.
+    /// Manual implementations of [`Validate`][`crate::Validate`] may use this to return early.
+    #[inline]
+    pub fn should_stop(&self) -> bool {
+        self.fail_fast && !self.errors.is_empty()
+    }
+
     /// Iterate over all `(Path, Error)` pairs.
     pub fn iter(&self) -> impl Iterator<Item = &(Path, Error)> {
         self.errors.iter()
     }
 
+    /// Iterate over the errors at the root of the report, which are not attached to any field.
+    ///
+    /// See [`Report::append_root`].
+    pub fn root_errors(&self) -> impl Iterator<Item = &Error> {
+        self.errors
+            .iter()
+            .filter(|(path, _)| path.is_empty())
+            .map(|(_, error)| error)
+    }
+
     /// Returns `true` if the report contains no validation errors.
     pub fn is_empty(&self) -> bool {
         self.errors.is_empty()
     }
 
+    /// Returns `Ok(())` if the report contains no validation errors, and `Err(self)` otherwise.
+    ///
+    /// ```rust
+    /// # use garde::{Error, Path, Report};
+    /// fn check_names(names: &[&str]) -> Result<(), Report> {
+    ///     let mut report = Report::new();
+    ///     for (index, name) in names.iter().enumerate() {
+    ///         if name.is_empty() {
+    ///             report.append(Path::new(index), Error::new("must not be empty"));
+    ///         }
+    ///     }
+    ///     report.into_result()
+    /// }
+    ///
+    /// assert!(check_names(&["alice", "bob"]).is_ok());
+    /// assert_eq!(
+    ///     check_names(&["alice", ""]).unwrap_err().to_string(),
+    ///     "[1]: must not be empty\n"
+    /// );
+    /// ```
+    pub fn into_result(self) -> Result<(), Report> {
+        match self.is_empty() {
+            true => Ok(()),
+            false => Err(self),
+        }
+    }
+
+    /// Retains only the errors for which `f` returns `true`, and removes the rest.
+    ///
+    /// The report is a flat list of errors, so removing every error at a path
+    /// leaves nothing behind for that path. Errors are kept in their original order.
+    ///
+    /// ```rust
+    /// # use garde::{Error, Path, Report};
+    /// let mut report = Report::new();
+    /// report.append(Path::new("name"), Error::new("length is lower than 1"));
+    /// report.append(Path::new("password"), Error::new("not ascii"));
+    ///
+    /// // Hide the errors of `password` in this view.
+    /// report.retain(|path, _| path.to_string() != "password");
+    /// assert_eq!(report.to_string(), "name: length is lower than 1\n");
+    /// ```
+    pub fn retain(&mut self, mut f: impl FnMut(&Path, &Error) -> bool) {
+        self.errors.retain(|(path, error)| f(path, error))
+    }
+
+    /// Returns the total number of errors in this report, across all paths.
+    ///
+    /// An empty report has a count of `0`.
+    pub fn count(&self) -> usize {
+        self.errors.len()
+    }
+
     /// Converts into the inner validation errors.
     pub fn into_inner(self) -> Vec<(Path, Error)> {
         self.errors
     }
+
+    /// Groups the error messages by their path, formatted as a JSON Pointer (see [`Path::to_json_pointer`]).
+    ///
+    /// Within each path, the messages are kept in their original order.
+    /// Errors at the root of the report, such as those appended with [`Report::append_root`], are grouped under `""`.
+    ///
+    /// ```rust
+    /// # use garde::{Error, Path, Report};
+    /// let mut report = Report::new();
+    /// report.append(Path::new("addresses").join(0usize).join("zip"), Error::new("not a number"));
+    /// report.append(Path::new("addresses").join(0usize).join("zip"), Error::new("too long"));
+    ///
+    /// let errors = report.flatten_json_pointer();
+    /// assert_eq!(errors["/addresses/0/zip"], ["not a number", "too long"]);
+    /// ```
+    pub fn flatten_json_pointer(&self) -> BTreeMap<String, Vec<String>> {
+        let mut errors = BTreeMap::<String, Vec<String>>::new();
+        for (path, error) in self.iter() {
+            errors
+                .entry(path.to_json_pointer())
+                .or_default()
+                .push(error.message().into());
+        }
+        errors
+    }
+
+    fn by_path(&self) -> BTreeMap<&Path, Vec<&Error>> {
+        let mut errors = BTreeMap::<&Path, Vec<&Error>>::new();
+        for (path, error) in self.iter() {
+            errors.entry(path).or_default().push(error);
+        }
+        errors
+    }
 }
 
-impl std::fmt::Display for Report {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// Formats the report as a flat list, with one `path: message` line per error.
+///
+/// The alternate flag (`{:#}`) instead formats the report as an indented tree,
+/// where each path component is written on its own line, followed by a colon.
+/// Nested components and messages are indented by two spaces relative to their parent,
+/// and list indices are written in brackets. For example:
+///
+/// ```text
+/// value is invalid
+/// user:
+///   name:
+///     length is lower than 1
+///   emails:
+///     [0]:
+///       not a valid email: value is missing `@`
+/// ```
+impl core::fmt::Display for Report {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            return ReportTree::new(self).fmt(f, 0);
+        }
+
         for (path, error) in self.iter() {
             if path.is_empty() {
                 writeln!(f, "{error}")?;
@@ -65,33 +288,230 @@ impl std::fmt::Display for Report {
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for Report {}
 
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// Two reports are equal if they contain the same errors at the same paths.
+///
+/// The order of errors at different paths does not matter, but the errors at a single path
+/// must be in the same order. This allows comparing a report with an expected one in tests:
+///
+/// ```rust
+/// # use garde::{Error, Path, Report};
+/// let mut actual = Report::new();
+/// actual.append(Path::new("name"), Error::new("length is lower than 1"));
+/// actual.append(Path::new("emails").join(0usize), Error::new("not a valid email"));
+///
+/// let mut expected = Report::new();
+/// expected.append(Path::new("emails").join(0usize), Error::new("not a valid email"));
+/// expected.append(Path::new("name"), Error::new("length is lower than 1"));
+///
+/// assert_eq!(actual, expected);
+/// ```
+impl PartialEq for Report {
+    fn eq(&self, other: &Self) -> bool {
+        self.errors.len() == other.errors.len() && self.by_path() == other.by_path()
+    }
+}
+
+impl Eq for Report {}
+
+/// Errors in a [`Report`] grouped by their path, in order of first appearance.
+#[derive(Default)]
+struct ReportTree<'a> {
+    errors: Vec<&'a Error>,
+    children: Vec<((Kind, &'a CompactString), ReportTree<'a>)>,
+}
+
+impl<'a> ReportTree<'a> {
+    fn new(report: &'a Report) -> Self {
+        let mut root = Self::default();
+        for (path, error) in report.iter() {
+            let mut node = &mut root;
+            for component in path.__iter().rev() {
+                if component.0 == Kind::None {
+                    continue;
+                }
+                let index = match node.children.iter().position(|(c, _)| *c == component) {
+                    Some(index) => index,
+                    None => {
+                        node.children.push((component, Self::default()));
+                        node.children.len() - 1
+                    }
+                };
+                node = &mut node.children[index].1;
+            }
+            node.errors.push(error);
+        }
+        root
+    }
+
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>, depth: usize) -> core::fmt::Result {
+        let indent = depth * 2;
+        for error in &self.errors {
+            writeln!(f, "{:indent$}{error}", "")?;
+        }
+        for ((kind, component), child) in &self.children {
+            match kind {
+                Kind::Index => writeln!(f, "{:indent$}[{component}]:", "")?,
+                _ => writeln!(f, "{:indent$}{component}:", "")?,
+            }
+            child.fmt(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// A single validation error.
+///
+/// An error may carry named params describing what was violated, see [`Error::with_param`].
+/// With the `std` feature, an error may also carry a typed cause, see [`Error::with_source`].
+/// Errors are compared by their message only.
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Error {
     message: CompactString,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    params: Vec<(CompactString, CompactString)>,
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    source: Option<std::sync::Arc<dyn std::error::Error + Send + Sync>>,
 }
 
 impl Error {
+    /// Creates an error with the given message.
+    ///
+    /// The message may be anything which can be turned into a string, such as a `&str`, a `String`, or a `Cow<str>`:
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    ///
+    /// let message: Cow<str> = Cow::Borrowed("not even");
+    /// assert_eq!(garde::Error::new(message).message(), "not even");
+    /// ```
     pub fn new(message: impl ToCompactString) -> Self {
         Self {
             message: message.to_compact_string(),
+            params: Vec::new(),
+            #[cfg(feature = "std")]
+            source: None,
         }
     }
 
+    /// Creates an error with the given message, caused by `source`.
+    ///
+    /// This allows custom validators to propagate a typed error, which can be recovered
+    /// through [`std::error::Error::source`]:
+    ///
+    /// ```rust
+    /// use std::error::Error as _;
+    ///
+    /// #[derive(Debug)]
+    /// struct TooShort;
+    ///
+    /// impl std::fmt::Display for TooShort {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    ///         write!(f, "too short")
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for TooShort {}
+    ///
+    /// let error = garde::Error::with_source("invalid name", TooShort);
+    /// assert_eq!(error.message(), "invalid name");
+    /// assert!(error.source().unwrap().is::<TooShort>());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn with_source(
+        message: impl ToCompactString,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        Self {
+            message: message.to_compact_string(),
+            params: Vec::new(),
+            source: Some(source.into().into()),
+        }
+    }
+
+    /// Returns the message of this error.
     pub fn message(&self) -> &str {
         self.message.as_ref()
     }
+
+    /// Attaches a named param to this error, replacing any previous value of the same param.
+    ///
+    /// Params describe what was violated, e.g. the bounds of a `range` rule, so that
+    /// a consumer can build its own (possibly localized) message:
+    ///
+    /// ```rust
+    /// let error = garde::Error::new("lower than 10")
+    ///     .with_param("min", 10)
+    ///     .with_param("value", 5);
+    /// assert_eq!(error.param("min"), Some("10"));
+    /// assert_eq!(error.param("max"), None);
+    /// ```
+    pub fn with_param(mut self, key: impl ToCompactString, value: impl ToCompactString) -> Self {
+        let key = key.to_compact_string();
+        let value = value.to_compact_string();
+        match self.params.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => *v = value,
+            None => self.params.push((key, value)),
+        }
+        self
+    }
+
+    /// Returns the value of the param named `key`, if this error has one.
+    pub fn param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k.as_str() == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Iterates over the params of this error, in the order they were attached.
+    pub fn params(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.params.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+    }
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl Eq for Error {}
+
+impl PartialOrd for Error {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Error {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.message.cmp(&other.message)
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "{}", self.message)
     }
 }
 
-impl std::error::Error for Error {}
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.source {
+            Some(source) => Some(&**source),
+            None => None,
+        }
+    }
+}
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Path {
@@ -115,13 +535,13 @@ pub enum Kind {
 #[derive(Default)]
 pub struct NoKey(());
 
-impl std::fmt::Display for NoKey {
-    fn fmt(&self, _: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for NoKey {
+    fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
         Ok(())
     }
 }
 
-pub trait PathComponentKind: std::fmt::Display + ToCompactString {
+pub trait PathComponentKind: core::fmt::Display + ToCompactString {
     fn component_kind() -> Kind;
 }
 
@@ -139,6 +559,7 @@ impl_path_component_kind!(usize => Index);
 impl_path_component_kind!(@'a; &'a str => Key);
 impl_path_component_kind!(@'a; Cow<'a, str> => Key);
 impl_path_component_kind!(String => Key);
+#[cfg(feature = "std")]
 impl_path_component_kind!(CompactString => Key);
 impl_path_component_kind!(NoKey => None);
 
@@ -149,26 +570,31 @@ impl<'a, T: PathComponentKind> PathComponentKind for &'a T {
 }
 
 impl Path {
+    /// Creates an empty path, which refers to the value being validated itself.
     pub fn empty() -> Self {
         Self {
             components: List::new(),
         }
     }
 
+    /// Returns the number of components in this path.
     pub fn len(&self) -> usize {
         self.components.len()
     }
 
+    /// Returns `true` if this path has no components.
     pub fn is_empty(&self) -> bool {
         self.components.is_empty()
     }
 
+    /// Creates a path with a single component, such as a field name or a list index.
     pub fn new<C: PathComponentKind>(component: C) -> Self {
         Self {
             components: List::new().append((C::component_kind(), component.to_compact_string())),
         }
     }
 
+    /// Creates a new path by appending `component` to this path.
     pub fn join<C: PathComponentKind>(&self, component: C) -> Self {
         Self {
             components: self
@@ -177,6 +603,43 @@ impl Path {
         }
     }
 
+    /// Creates a new path by appending all components of `other` to this path.
+    pub fn concat(&self, other: &Path) -> Self {
+        let mut components = self.components.clone();
+        for (kind, component) in other.__iter().rev() {
+            components = components.append((kind, component.clone()));
+        }
+        Self { components }
+    }
+
+    /// Formats this path as a [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901), e.g. `/addresses/0/zip`.
+    ///
+    /// `~` and `/` in keys are escaped as `~0` and `~1`. Components without a key, such as the field
+    /// of a newtype, are left out, and the empty path is formatted as an empty string.
+    ///
+    /// ```rust
+    /// # use garde::Path;
+    /// let path = Path::new("a/b").join("m~n").join(0usize);
+    /// assert_eq!(path.to_json_pointer(), "/a~1b/m~0n/0");
+    /// ```
+    pub fn to_json_pointer(&self) -> String {
+        let mut pointer = String::new();
+        for (kind, component) in self.__iter().rev() {
+            if kind == Kind::None {
+                continue;
+            }
+            pointer.push('/');
+            for c in component.chars() {
+                match c {
+                    '~' => pointer.push_str("~0"),
+                    '/' => pointer.push_str("~1"),
+                    c => pointer.push(c),
+                }
+            }
+        }
+        pointer
+    }
+
     #[doc(hidden)]
     pub fn __iter(
         &self,
@@ -191,14 +654,14 @@ impl Path {
 
 type TempComponents<'a> = SmallVec<[(Kind, &'a CompactString); 8]>;
 
-impl std::fmt::Debug for Path {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for Path {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         struct Components<'a> {
             path: &'a Path,
         }
 
-        impl<'a> std::fmt::Debug for Components<'a> {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl<'a> core::fmt::Debug for Components<'a> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 let mut list = f.debug_list();
                 list.entries(self.path.__iter().rev().map(|(_, c)| c))
                     .finish()
@@ -211,8 +674,8 @@ impl std::fmt::Debug for Path {
     }
 }
 
-impl std::fmt::Display for Path {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Path {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         let mut components = self.__iter().rev().peekable();
         let mut first = true;
         while let Some((kind, component)) = components.next() {
@@ -272,17 +735,124 @@ impl<'de> serde::Deserialize<'de> for Path {
 mod tests {
     use super::*;
 
+    // Required to convert into `anyhow::Error` or `Box<dyn std::error::Error + Send + Sync>`.
     const _: () = {
-        fn assert<T: Send>() {}
+        fn assert<T: Send + Sync + 'static>() {}
         let _ = assert::<Report>;
+        let _ = assert::<Error>;
     };
 
+    #[test]
+    fn report_eq() {
+        let mut a = Report::new();
+        a.append(Path::new("a"), Error::new("first"));
+        a.append(Path::new("b"), Error::new("other"));
+        a.append(Path::new("a"), Error::new("second"));
+
+        let mut b = Report::new();
+        b.append(Path::new("b"), Error::new("other"));
+        b.append(Path::new("a"), Error::new("first"));
+        b.append(Path::new("a"), Error::new("second"));
+        assert_eq!(a, b);
+
+        let mut c = Report::new();
+        c.append(Path::new("a"), Error::new("second"));
+        c.append(Path::new("a"), Error::new("first"));
+        c.append(Path::new("b"), Error::new("other"));
+        assert_ne!(a, c);
+
+        assert_ne!(a, Report::new());
+        assert_eq!(Report::new(), Report::fail_fast());
+    }
+
+    #[test]
+    fn report_root_errors() {
+        let mut report = Report::new();
+        report.append(Path::new("a"), Error::new("field"));
+        report.append_root(Error::new("first"));
+        report.append(Path::new(NoKey::default()), Error::new("newtype"));
+        report.append_root(Error::new("second"));
+
+        let root = report.root_errors().map(Error::message).collect::<Vec<_>>();
+        assert_eq!(root, ["first", "second"]);
+        assert_eq!(
+            report.flatten_json_pointer()[""],
+            ["first", "newtype", "second"]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn report_into_anyhow() {
+        fn validate() -> Result<(), Report> {
+            let mut report = Report::new();
+            report.append(Path::new("name"), Error::new("length is lower than 1"));
+            report.append_root(Error::new("passwords do not match"));
+            report.into_result()
+        }
+
+        fn run() -> anyhow::Result<()> {
+            validate()?;
+            Ok(())
+        }
+
+        let error = run().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "name: length is lower than 1\npasswords do not match\n"
+        );
+        assert!(error.downcast_ref::<Report>().is_some());
+
+        let boxed: Box<dyn std::error::Error + Send + Sync> = validate().unwrap_err().into();
+        assert_eq!(boxed.to_string(), error.to_string());
+    }
+
     #[test]
     fn path_join() {
         let path = Path::new("a").join("b").join("c");
         assert_eq!(path.to_string(), "a.b.c");
     }
 
+    #[test]
+    fn path_concat() {
+        let path = Path::new("a").concat(&Path::new("b").join(0usize).join("c"));
+        assert_eq!(path.to_string(), "a.b[0].c");
+        assert_eq!(Path::empty().concat(&path), path);
+        assert_eq!(path.concat(&Path::empty()), path);
+    }
+
+    #[test]
+    fn path_json_pointer() {
+        assert_eq!(Path::empty().to_json_pointer(), "");
+        assert_eq!(
+            Path::new("addresses")
+                .join(0usize)
+                .join("zip")
+                .to_json_pointer(),
+            "/addresses/0/zip"
+        );
+        assert_eq!(
+            Path::new("a/b").join("~").join("~1/").to_json_pointer(),
+            "/a~1b/~0/~01~1"
+        );
+        assert_eq!(Path::new(NoKey::default()).join("").to_json_pointer(), "/");
+    }
+
+    #[test]
+    fn report_flatten_json_pointer() {
+        let mut report = Report::new();
+        report.append(Path::new("a/b").join(1usize), Error::new("first"));
+        report.append(Path::new("c~d"), Error::new("second"));
+        report.append(Path::new("a/b").join(1usize), Error::new("third"));
+        report.append(Path::empty(), Error::new("fourth"));
+
+        let errors = report.flatten_json_pointer();
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors["/a~1b/1"], ["first", "third"]);
+        assert_eq!(errors["/c~0d"], ["second"]);
+        assert_eq!(errors[""], ["fourth"]);
+    }
+
     #[test]
     fn report_select() {
         let mut report = Report::new();
@@ -305,6 +875,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn report_count() {
+        let mut report = Report::new();
+        assert_eq!(report.count(), 0);
+
+        report.append(Path::new("a"), Error::new("lorem"));
+        report.append(Path::new("a"), Error::new("ipsum"));
+        report.append(Path::new("b").join(0usize).join("c"), Error::new("dolor"));
+        report.append(Path::new("b").join(1usize).join("c"), Error::new("sit"));
+        assert_eq!(report.count(), 4);
+
+        let mut fail_fast = Report::fail_fast();
+        fail_fast.append(Path::new("a"), Error::new("lorem"));
+        fail_fast.append(Path::new("b"), Error::new("ipsum"));
+        assert_eq!(fail_fast.count(), 1);
+    }
+
+    #[test]
+    fn report_retain() {
+        let mut report = Report::new();
+        report.append(Path::new("a"), Error::new("lorem"));
+        report.append(Path::new("b").join(0usize), Error::new("ipsum"));
+        report.append(Path::new("b").join(1usize), Error::new("dolor"));
+        report.append(Path::new("c"), Error::new("lorem"));
+
+        report.retain(|path, _| path.to_string() != "b[0]");
+        assert_eq!(report.to_string(), "a: lorem\nb[1]: dolor\nc: lorem\n");
+
+        report.retain(|_, error| error.message() != "lorem");
+        assert_eq!(report.to_string(), "b[1]: dolor\n");
+
+        report.retain(|_, _| false);
+        assert!(report.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn error_with_source() {
+        use std::error::Error as _;
+
+        let source = "12a".parse::<u32>().unwrap_err();
+        let error = Error::with_source("not a number", source.clone());
+        assert_eq!(error.to_string(), "not a number");
+        assert_eq!(
+            error
+                .source()
+                .unwrap()
+                .downcast_ref::<core::num::ParseIntError>(),
+            Some(&source)
+        );
+        assert_eq!(error, Error::new("not a number"));
+        assert!(Error::new("not a number").source().is_none());
+    }
+
     #[cfg(feature = "serde")]
     mod serde {
         use super::*;
@@ -0,0 +1,16 @@
+//! Stand-ins for the [`compact_str`](https://docs.rs/compact_str) types used when the `std` feature is disabled.
+
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+
+pub type CompactString = String;
+
+pub trait ToCompactString {
+    fn to_compact_string(&self) -> CompactString;
+}
+
+impl<T: Display + ?Sized> ToCompactString for T {
+    fn to_compact_string(&self) -> CompactString {
+        self.to_string()
+    }
+}
@@ -1,4 +1,7 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 pub mod error;
 pub mod rules;
@@ -11,15 +14,73 @@ pub use validate::{Unvalidated, Valid, Validate};
 
 pub type Result = ::core::result::Result<(), Error>;
 
+/// The types needed to write and use custom validators.
+///
+/// ```rust
+/// use garde::prelude::*;
+///
+/// fn is_even(value: &u32, _: &()) -> garde::Result {
+///     match value % 2 {
+///         0 => Ok(()),
+///         _ => Err(Error::new(format!("{value} is not even"))),
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct Test {
+///     #[garde(custom(is_even))]
+///     value: u32,
+/// }
+///
+/// let report: Report = Test { value: 3 }.validate().unwrap_err();
+/// assert_eq!(report.to_string(), "value: 3 is not even\n");
+/// ```
+pub mod prelude {
+    pub use crate::error::{Error, Path, Report};
+    pub use crate::validate::{Unvalidated, Valid, Validate};
+    #[cfg(feature = "derive")]
+    pub use garde_derive::Validate;
+}
+
 pub mod external {
-    pub use {compact_str, smallvec};
+    #[cfg(feature = "bytes")]
+    pub use bytes;
+    #[cfg(feature = "std")]
+    pub use compact_str;
+    #[cfg(feature = "indexmap")]
+    pub use indexmap;
+    pub use smallvec;
+    #[cfg(feature = "tracing")]
+    pub use tracing;
 }
 
-#[doc(hidden)]
+/// Utilities for implementing [`Validate`] manually.
 pub mod util {
-    use crate::error::PathComponentKind;
+    use crate::error::{Error, PathComponentKind};
     use crate::Path;
 
+    /// Calls `f`, and converts a panic into an [`Error`], for `#[garde(catch_unwind)]`.
+    #[doc(hidden)]
+    #[cfg(feature = "std")]
+    pub fn __catch_unwind<T>(f: impl FnOnce() -> T) -> Result<T, Error> {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(f))
+            .map_err(|_| Error::new("validation panicked"))
+    }
+
+    /// Asserts that a `#[garde(custom(path))]` function has the signature of a custom rule,
+    /// so that a mismatch is reported at `path` rather than at the derive.
+    #[doc(hidden)]
+    #[inline]
+    pub fn __custom_fn<T, C, F>(f: F) -> F
+    where
+        T: ?Sized,
+        C: ?Sized,
+        F: Fn(&T, &C) -> Result<(), Error>,
+    {
+        f
+    }
+
+    #[doc(hidden)]
     #[inline]
     pub fn __make_nested_path<'a, C: PathComponentKind + Clone + 'a>(
         mut parent: impl FnMut() -> Path + 'a,
@@ -31,6 +92,13 @@ pub mod util {
         move || MaybeJoin::maybe_join(&mut nested, &mut parent, || component.clone())
     }
 
+    /// Creates a lazily-constructed path to a nested value, given the path of its parent and a key.
+    ///
+    /// `$parent` is the `FnMut() -> Path` received by [`Validate::validate_into`][`crate::Validate::validate_into`],
+    /// and `$key` is any [`PathComponentKind`][`crate::error::PathComponentKind`], such as a field name or a list index.
+    /// The path is only constructed if an error is reported.
+    ///
+    /// See [`Report`][`crate::Report`] for an example.
     #[doc(hidden)]
     #[macro_export]
     macro_rules! __nested_path {
@@ -39,8 +107,10 @@ pub mod util {
         };
     }
 
+    #[doc(inline)]
     pub use crate::__nested_path as nested_path;
 
+    #[doc(hidden)]
     pub trait MaybeJoin {
         fn maybe_join<C, P, CF>(&mut self, parent: P, component: CF) -> Path
         where
@@ -22,6 +22,18 @@ pub fn apply<T: Alphanumeric>(v: &T, _: ()) -> Result<(), Error> {
     Ok(())
 }
 
+/// Returns `true` if `v` only contains alphanumeric characters.
+///
+/// This is the standalone equivalent of the `#[garde(alphanumeric)]` rule.
+///
+/// ```rust
+/// assert!(garde::rules::alphanumeric::is_valid("abc123"));
+/// assert!(!garde::rules::alphanumeric::is_valid("abc-123"));
+/// ```
+pub fn is_valid(v: &str) -> bool {
+    apply(&v, ()).is_ok()
+}
+
 pub trait Alphanumeric {
     fn validate_alphanumeric(&self) -> bool;
 }
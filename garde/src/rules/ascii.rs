@@ -11,6 +11,18 @@
 //! The entrypoint is the [`Ascii`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(ascii)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+//!
+//! The `printable` mode only allows printable ASCII characters (`0x20..=0x7E`), which excludes control characters:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(ascii(printable))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! This mode uses the [`printable::PrintableAscii`] trait, which also has a blanket implementation for all `T: garde::rules::AsStr`.
 
 use super::AsStr;
 use crate::error::Error;
@@ -22,6 +34,18 @@ pub fn apply<T: Ascii>(v: &T, _: ()) -> Result<(), Error> {
     Ok(())
 }
 
+/// Returns `true` if `v` only contains ASCII characters.
+///
+/// This is the standalone equivalent of the `#[garde(ascii)]` rule.
+///
+/// ```rust
+/// assert!(garde::rules::ascii::is_valid("abc"));
+/// assert!(!garde::rules::ascii::is_valid("åbc"));
+/// ```
+pub fn is_valid(v: &str) -> bool {
+    apply(&v, ()).is_ok()
+}
+
 pub trait Ascii {
     fn validate_ascii(&self) -> bool;
 }
@@ -40,3 +64,46 @@ impl<T: Ascii> Ascii for Option<T> {
         }
     }
 }
+
+pub mod printable {
+    use super::AsStr;
+    use crate::error::Error;
+
+    pub fn apply<T: PrintableAscii>(v: &T, _: ()) -> Result<(), Error> {
+        if !v.validate_printable_ascii() {
+            return Err(Error::new("must be printable ASCII"));
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if `v` only contains printable ASCII characters.
+    ///
+    /// This is the standalone equivalent of the `#[garde(ascii(printable))]` rule.
+    ///
+    /// ```rust
+    /// assert!(garde::rules::ascii::printable::is_valid("abc"));
+    /// assert!(!garde::rules::ascii::printable::is_valid("a\tbc"));
+    /// ```
+    pub fn is_valid(v: &str) -> bool {
+        apply(&v, ()).is_ok()
+    }
+
+    pub trait PrintableAscii {
+        fn validate_printable_ascii(&self) -> bool;
+    }
+
+    impl<T: AsStr> PrintableAscii for T {
+        fn validate_printable_ascii(&self) -> bool {
+            self.as_str().bytes().all(|b| matches!(b, 0x20..=0x7E))
+        }
+    }
+
+    impl<T: PrintableAscii> PrintableAscii for Option<T> {
+        fn validate_printable_ascii(&self) -> bool {
+            match self {
+                Some(value) => value.validate_printable_ascii(),
+                None => true,
+            }
+        }
+    }
+}
@@ -0,0 +1,302 @@
+//! Human-readable byte size validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(byte_size(min="1KB", max="10MB"))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The value must be a number, optionally followed by a unit. Decimal (`KB`, `MB`, `GB`, `TB`, `PB`)
+//! and binary (`KiB`, `MiB`, `GiB`, `TiB`, `PiB`) units are supported, and are matched case-insensitively.
+//! A value without a unit, or with the `B` unit, is a number of bytes.
+//!
+//! The bounds use the same format, and are parsed at compile time. An invalid bound, or a `min` greater than `max`, is a compile error.
+//!
+//! This rule requires the `byte-size` feature.
+//!
+//! The entrypoint is the [`ByteSize`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(byte_size(...))]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use alloc::format;
+use core::fmt::Display;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: ByteSize>(v: &T, (min, max): (Option<Size>, Option<Size>)) -> Result<(), Error> {
+    let lower_bound = min.map_or(0, |size| size.bytes());
+    let upper_bound = max.map_or(u64::MAX, |size| size.bytes());
+    match v.validate_byte_size(lower_bound, upper_bound) {
+        Ok(()) => Ok(()),
+        Err(InvalidByteSize::Parse(e)) => Err(Error::new(format!("not a valid byte size: {e}"))),
+        Err(InvalidByteSize::Lower) => Err(Error::new(format!(
+            "lower than minimum size {}",
+            min.map_or("0B", |size| size.as_str())
+        ))),
+        Err(InvalidByteSize::Upper) => Err(Error::new(format!(
+            "exceeds maximum size {}",
+            max.map_or("", |size| size.as_str())
+        ))),
+    }
+}
+
+pub trait ByteSize {
+    fn validate_byte_size(&self, lower_bound: u64, upper_bound: u64)
+        -> Result<(), InvalidByteSize>;
+}
+
+impl<T: AsStr> ByteSize for T {
+    fn validate_byte_size(
+        &self,
+        lower_bound: u64,
+        upper_bound: u64,
+    ) -> Result<(), InvalidByteSize> {
+        let size = parse_byte_size(self.as_str()).map_err(InvalidByteSize::Parse)?;
+        if size < lower_bound {
+            Err(InvalidByteSize::Lower)
+        } else if size > upper_bound {
+            Err(InvalidByteSize::Upper)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<T: ByteSize> ByteSize for Option<T> {
+    fn validate_byte_size(
+        &self,
+        lower_bound: u64,
+        upper_bound: u64,
+    ) -> Result<(), InvalidByteSize> {
+        match self {
+            Some(value) => value.validate_byte_size(lower_bound, upper_bound),
+            None => Ok(()),
+        }
+    }
+}
+
+pub enum InvalidByteSize {
+    Parse(ParseByteSizeError),
+    Lower,
+    Upper,
+}
+
+/// A byte size bound, along with the string it was parsed from.
+#[derive(Clone, Copy, Debug)]
+pub struct Size {
+    bytes: u64,
+    repr: &'static str,
+}
+
+impl Size {
+    /// Parses a byte size bound.
+    ///
+    /// This is intended to be used in a `const` context, where it fails compilation if `repr` is not a valid byte size.
+    pub const fn new(repr: &'static str) -> Self {
+        match parse(repr.as_bytes()) {
+            Ok(bytes) => Self { bytes, repr },
+            Err(_) => panic!("invalid byte size"),
+        }
+    }
+
+    /// Creates a bound which was already parsed by the derive.
+    #[doc(hidden)]
+    pub const fn parsed(bytes: u64, repr: &'static str) -> Self {
+        Self { bytes, repr }
+    }
+
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        self.repr
+    }
+}
+
+impl Display for Size {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.repr)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParseByteSizeError {
+    Empty,
+    InvalidNumber,
+    InvalidUnit,
+    Overflow,
+}
+
+impl Display for ParseByteSizeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseByteSizeError::Empty => write!(f, "value is empty"),
+            ParseByteSizeError::InvalidNumber => write!(f, "invalid number"),
+            ParseByteSizeError::InvalidUnit => write!(f, "unknown unit"),
+            ParseByteSizeError::Overflow => write!(f, "value is too large"),
+        }
+    }
+}
+
+/// Parses a human-readable byte size, such as `10MB` or `1.5 GiB`, into a number of bytes.
+pub fn parse_byte_size(s: &str) -> Result<u64, ParseByteSizeError> {
+    parse(s.as_bytes())
+}
+
+const UNITS: &[(&[u8], u64)] = &[
+    (b"", 1),
+    (b"b", 1),
+    (b"kb", 1_000),
+    (b"mb", 1_000_000),
+    (b"gb", 1_000_000_000),
+    (b"tb", 1_000_000_000_000),
+    (b"pb", 1_000_000_000_000_000),
+    (b"kib", 1 << 10),
+    (b"mib", 1 << 20),
+    (b"gib", 1 << 30),
+    (b"tib", 1 << 40),
+    (b"pib", 1 << 50),
+];
+
+// Fractional digits beyond this precision are ignored.
+const MAX_FRACTION_SCALE: u64 = 1_000_000_000_000;
+
+// This is a `const fn` so that bounds may be parsed at compile time,
+// which is why it's written using `while` loops and manual indexing.
+const fn parse(s: &[u8]) -> Result<u64, ParseByteSizeError> {
+    let mut start = 0;
+    let mut end = s.len();
+    while start < end && s[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    while end > start && s[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    if start == end {
+        return Err(ParseByteSizeError::Empty);
+    }
+
+    let mut i = start;
+    let mut num_digits = 0;
+
+    let mut whole: u64 = 0;
+    while i < end && s[i].is_ascii_digit() {
+        whole = match whole.checked_mul(10) {
+            Some(v) => match v.checked_add((s[i] - b'0') as u64) {
+                Some(v) => v,
+                None => return Err(ParseByteSizeError::Overflow),
+            },
+            None => return Err(ParseByteSizeError::Overflow),
+        };
+        num_digits += 1;
+        i += 1;
+    }
+
+    let mut fraction: u64 = 0;
+    let mut scale: u64 = 1;
+    if i < end && s[i] == b'.' {
+        i += 1;
+        while i < end && s[i].is_ascii_digit() {
+            if scale < MAX_FRACTION_SCALE {
+                fraction = fraction * 10 + (s[i] - b'0') as u64;
+                scale *= 10;
+            }
+            num_digits += 1;
+            i += 1;
+        }
+    }
+
+    if num_digits == 0 {
+        return Err(ParseByteSizeError::InvalidNumber);
+    }
+
+    while i < end && s[i].is_ascii_whitespace() {
+        i += 1;
+    }
+
+    let multiplier = match find_unit(s, i, end) {
+        Some(multiplier) => multiplier,
+        None => return Err(ParseByteSizeError::InvalidUnit),
+    };
+
+    let whole = match whole.checked_mul(multiplier) {
+        Some(v) => v,
+        None => return Err(ParseByteSizeError::Overflow),
+    };
+    let fraction = (fraction as u128 * multiplier as u128 / scale as u128) as u64;
+    match whole.checked_add(fraction) {
+        Some(v) => Ok(v),
+        None => Err(ParseByteSizeError::Overflow),
+    }
+}
+
+const fn find_unit(s: &[u8], start: usize, end: usize) -> Option<u64> {
+    let mut u = 0;
+    while u < UNITS.len() {
+        let (name, multiplier) = UNITS[u];
+        if name.len() == end - start {
+            let mut i = 0;
+            while i < name.len() && s[start + i].to_ascii_lowercase() == name[i] {
+                i += 1;
+            }
+            if i == name.len() {
+                return Some(multiplier);
+            }
+        }
+        u += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_byte_size() {
+        let tests = &[
+            ("0", Ok(0)),
+            ("100", Ok(100)),
+            ("100B", Ok(100)),
+            ("10MB", Ok(10_000_000)),
+            ("10 mb", Ok(10_000_000)),
+            (" 10MB ", Ok(10_000_000)),
+            ("1KiB", Ok(1024)),
+            ("1.5KB", Ok(1_500)),
+            ("1.5 KiB", Ok(1_536)),
+            (".5KB", Ok(500)),
+            ("2GiB", Ok(2 * 1024 * 1024 * 1024)),
+            ("1PB", Ok(1_000_000_000_000_000)),
+            ("", Err(ParseByteSizeError::Empty)),
+            ("   ", Err(ParseByteSizeError::Empty)),
+            ("MB", Err(ParseByteSizeError::InvalidNumber)),
+            (".MB", Err(ParseByteSizeError::InvalidNumber)),
+            ("-1MB", Err(ParseByteSizeError::InvalidNumber)),
+            ("10XB", Err(ParseByteSizeError::InvalidUnit)),
+            ("10 M B", Err(ParseByteSizeError::InvalidUnit)),
+            ("1.2.3MB", Err(ParseByteSizeError::InvalidUnit)),
+            ("18446744073709551616", Err(ParseByteSizeError::Overflow)),
+            ("100000PB", Err(ParseByteSizeError::Overflow)),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(
+                &parse_byte_size(input),
+                expected,
+                "Byte size `{}` was not parsed correctly",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_size_const() {
+        const SIZE: Size = Size::new("10MB");
+        assert_eq!(SIZE.bytes(), 10_000_000);
+        assert_eq!(SIZE.to_string(), "10MB");
+    }
+}
@@ -0,0 +1,72 @@
+//! Character set validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(charset(latin1))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The rule fails if any character is above the highest code point of the given set:
+//! - `ascii` allows code points up to `U+007F`
+//! - `latin1` allows code points up to `U+00FF`
+//! - `max = <expr>` allows code points up to the given `u32`, e.g. `charset(max = 0x7FF)`
+//!
+//! The entrypoint is the [`MaxCodepoint`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(charset)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use alloc::format;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: MaxCodepoint>(v: &T, (charset,): (Charset,)) -> Result<(), Error> {
+    if !v.validate_max_codepoint(charset.max()) {
+        return Err(match charset {
+            Charset::Ascii => Error::new("contains characters outside the ASCII range"),
+            Charset::Latin1 => Error::new("contains characters outside the Latin-1 range"),
+            Charset::Max(max) => Error::new(format!("contains characters above U+{max:04X}")),
+        });
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Charset {
+    Ascii,
+    Latin1,
+    Max(u32),
+}
+
+impl Charset {
+    /// Returns the highest code point in this character set.
+    pub fn max(&self) -> u32 {
+        match self {
+            Charset::Ascii => 0x7F,
+            Charset::Latin1 => 0xFF,
+            Charset::Max(max) => *max,
+        }
+    }
+}
+
+pub trait MaxCodepoint {
+    /// Returns `true` if no character is above `max`.
+    fn validate_max_codepoint(&self, max: u32) -> bool;
+}
+
+impl<T: AsStr> MaxCodepoint for T {
+    fn validate_max_codepoint(&self, max: u32) -> bool {
+        self.as_str().chars().all(|c| c as u32 <= max)
+    }
+}
+
+impl<T: MaxCodepoint> MaxCodepoint for Option<T> {
+    fn validate_max_codepoint(&self, max: u32) -> bool {
+        match self {
+            Some(value) => value.validate_max_codepoint(max),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! CIDR block validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(cidr)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! A CIDR block is an IP address followed by a `/` and a prefix length, such as `10.0.0.0/24` or `2001:db8::/32`.
+//! The prefix length may be at most 32 for IPv4 and 128 for IPv6 addresses. The bits after the prefix are not required to be zero.
+//! Like `ip`, this rule comes in three variants: `cidr` accepts both address families, while `cidrv4` and `cidrv6` only accept one.
+//!
+//! The entrypoint is the [`Cidr`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(cidr)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use std::fmt::Display;
+use std::net::IpAddr;
+
+use super::ip::IpKind;
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Cidr>(v: &T, (kind,): (IpKind,)) -> Result<(), Error> {
+    if v.validate_cidr(kind).is_err() {
+        return Err(Error::new(match kind {
+            IpKind::Any => "not a valid CIDR block",
+            IpKind::V4 => "not a valid IPv4 CIDR block",
+            IpKind::V6 => "not a valid IPv6 CIDR block",
+        }));
+    }
+    Ok(())
+}
+
+/// Returns `true` if `v` is a valid CIDR block of the given `kind`.
+///
+/// This is the standalone equivalent of the `#[garde(cidr)]`, `#[garde(cidrv4)]` and `#[garde(cidrv6)]` rules.
+///
+/// ```rust
+/// use garde::rules::cidr::is_valid;
+/// use garde::rules::ip::IpKind;
+///
+/// assert!(is_valid("10.0.0.0/24", IpKind::Any));
+/// assert!(!is_valid("10.0.0.0/33", IpKind::Any));
+/// assert!(!is_valid("10.0.0.0/24", IpKind::V6));
+/// ```
+pub fn is_valid(v: &str, kind: IpKind) -> bool {
+    apply(&v, (kind,)).is_ok()
+}
+
+pub trait Cidr {
+    type Error: Display;
+
+    fn validate_cidr(&self, kind: IpKind) -> Result<(), Self::Error>;
+}
+
+/// The error returned by the [`Cidr`] implementation for strings.
+#[derive(Debug, Clone, Copy)]
+pub struct InvalidCidr;
+
+impl Display for InvalidCidr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid CIDR block")
+    }
+}
+
+impl<T: AsStr> Cidr for T {
+    type Error = InvalidCidr;
+
+    fn validate_cidr(&self, kind: IpKind) -> Result<(), Self::Error> {
+        let (addr, prefix) = self.as_str().split_once('/').ok_or(InvalidCidr)?;
+        let addr = addr.parse::<IpAddr>().map_err(|_| InvalidCidr)?;
+        let max_prefix = match (kind, addr) {
+            (IpKind::Any | IpKind::V4, IpAddr::V4(_)) => 32,
+            (IpKind::Any | IpKind::V6, IpAddr::V6(_)) => 128,
+            _ => return Err(InvalidCidr),
+        };
+        if prefix.is_empty() || prefix.len() > 3 || !prefix.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(InvalidCidr);
+        }
+        let prefix = prefix.parse::<u8>().map_err(|_| InvalidCidr)?;
+        if prefix > max_prefix {
+            return Err(InvalidCidr);
+        }
+        Ok(())
+    }
+}
+
+impl<T: Cidr> Cidr for Option<T> {
+    type Error = T::Error;
+
+    fn validate_cidr(&self, kind: IpKind) -> Result<(), Self::Error> {
+        match self {
+            Some(value) => value.validate_cidr(kind),
+            None => Ok(()),
+        }
+    }
+}
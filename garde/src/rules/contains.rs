@@ -12,10 +12,23 @@
 //! }
 //! ```
 //!
+//! The needle may also be a `char` literal, which is searched for as a single character.
+//! This is still the `contains` rule, so it may not be combined with another `contains` on the same field:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(contains('@'))]
+//!     v: String,
+//! }
+//! ```
+//!
 //! The entrypoint is the [`Contains`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(contains)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
 
+use alloc::format;
+
 use super::AsStr;
 use crate::error::Error;
 
@@ -28,12 +41,23 @@ pub fn apply<T: Contains>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
 
 pub trait Contains {
     fn validate_contains(&self, pat: &str) -> bool;
+
+    /// Used when the needle is a `char` literal.
+    ///
+    /// The default implementation passes the UTF-8 encoding of `pat` to [`Contains::validate_contains`].
+    fn validate_contains_char(&self, pat: char) -> bool {
+        self.validate_contains(pat.encode_utf8(&mut [0; 4]))
+    }
 }
 
 impl<T: AsStr> Contains for T {
     fn validate_contains(&self, pat: &str) -> bool {
         self.as_str().contains(pat)
     }
+
+    fn validate_contains_char(&self, pat: char) -> bool {
+        self.as_str().contains(pat)
+    }
 }
 
 impl<T: Contains> Contains for Option<T> {
@@ -43,4 +67,26 @@ impl<T: Contains> Contains for Option<T> {
             None => true,
         }
     }
+
+    fn validate_contains_char(&self, pat: char) -> bool {
+        match self {
+            Some(value) => value.validate_contains_char(pat),
+            None => true,
+        }
+    }
+}
+
+/// `contains` with a `char` literal as the needle.
+pub mod single_char {
+    use alloc::format;
+
+    use super::Contains;
+    use crate::error::Error;
+
+    pub fn apply<T: Contains>(v: &T, (pat,): (char,)) -> Result<(), Error> {
+        if !v.validate_contains_char(pat) {
+            return Err(Error::new(format!("does not contain '{pat}'")));
+        }
+        Ok(())
+    }
 }
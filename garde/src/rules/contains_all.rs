@@ -0,0 +1,28 @@
+//! Substring set validation, requiring all of the substrings.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(contains_all(["foo", "bar"]))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The substrings must be non-empty string literals, and there must be at least one of them.
+//!
+//! This rule uses the [`Contains`] trait, so it may be used with any type which supports the `#[garde(contains)]` rule.
+
+use alloc::format;
+
+use super::contains::Contains;
+use crate::error::Error;
+
+pub fn apply<T: Contains>(v: &T, (pats,): (&[&str],)) -> Result<(), Error> {
+    if !pats.iter().all(|pat| v.validate_contains(pat)) {
+        return Err(Error::new(format!(
+            "must contain all of: {}",
+            pats.join(", ")
+        )));
+    }
+    Ok(())
+}
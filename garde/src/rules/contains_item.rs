@@ -0,0 +1,97 @@
+//! Collection membership validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(contains_item("admin"))]
+//!     v: Vec<String>,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`ContainsItem`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(contains_item)]` rule.
+//!
+//! This trait is implemented for slices, arrays, and the standard collections, whenever their items
+//! can be compared to the needle using `PartialEq`.
+//!
+//! Unlike `#[garde(contains)]`, which checks for a substring, this rule checks that one of the items is equal to the given value.
+
+use alloc::format;
+use core::fmt::Debug;
+
+use crate::error::Error;
+
+pub fn apply<T: ContainsItem<U>, U: Debug + ?Sized>(v: &T, (item,): (&U,)) -> Result<(), Error> {
+    if !v.validate_contains_item(item) {
+        return Err(Error::new(format!("does not contain {item:?}")));
+    }
+    Ok(())
+}
+
+pub trait ContainsItem<U: ?Sized> {
+    fn validate_contains_item(&self, item: &U) -> bool;
+}
+
+macro_rules! impl_via_iter {
+    (in<$($generic:ident),*> $T:ty) => {
+        impl<$($generic,)* U: ?Sized> ContainsItem<U> for $T
+        where
+            T: PartialEq<U>,
+        {
+            fn validate_contains_item(&self, item: &U) -> bool {
+                self.iter().any(|v| v == item)
+            }
+        }
+    };
+}
+
+impl_via_iter!(in<T> [T]);
+impl_via_iter!(in<T> alloc::vec::Vec<T>);
+#[cfg(feature = "std")]
+impl_via_iter!(in<T, S> std::collections::HashSet<T, S>);
+impl_via_iter!(in<T> alloc::collections::BTreeSet<T>);
+impl_via_iter!(in<T> alloc::collections::VecDeque<T>);
+impl_via_iter!(in<T> alloc::collections::LinkedList<T>);
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array, U: ?Sized> ContainsItem<U> for smallvec::SmallVec<A>
+where
+    A::Item: PartialEq<U>,
+{
+    fn validate_contains_item(&self, item: &U) -> bool {
+        self.iter().any(|v| v == item)
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, U: ?Sized, const CAP: usize> ContainsItem<U> for arrayvec::ArrayVec<T, CAP>
+where
+    T: PartialEq<U>,
+{
+    fn validate_contains_item(&self, item: &U) -> bool {
+        self.iter().any(|v| v == item)
+    }
+}
+
+impl<const N: usize, T, U: ?Sized> ContainsItem<U> for [T; N]
+where
+    T: PartialEq<U>,
+{
+    fn validate_contains_item(&self, item: &U) -> bool {
+        self.iter().any(|v| v == item)
+    }
+}
+
+impl<T: ContainsItem<U> + ?Sized, U: ?Sized> ContainsItem<U> for &T {
+    fn validate_contains_item(&self, item: &U) -> bool {
+        T::validate_contains_item(self, item)
+    }
+}
+
+impl<T: ContainsItem<U>, U: ?Sized> ContainsItem<U> for Option<T> {
+    fn validate_contains_item(&self, item: &U) -> bool {
+        match self {
+            Some(value) => value.validate_contains_item(item),
+            None => true,
+        }
+    }
+}
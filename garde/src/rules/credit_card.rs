@@ -11,6 +11,8 @@
 //! The entrypoint is the [`CreditCard`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(credit_card)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+//!
+//! To also find out which network a card number belongs to, use [`detect_network`].
 
 use std::fmt::Display;
 
@@ -24,6 +26,87 @@ pub fn apply<T: CreditCard>(v: &T, _: ()) -> Result<(), Error> {
     Ok(())
 }
 
+/// Returns `true` if `v` is a valid credit card number.
+///
+/// This is the standalone equivalent of the `#[garde(credit_card)]` rule.
+///
+/// ```rust
+/// assert!(garde::rules::credit_card::is_valid("4539571147647251"));
+/// assert!(!garde::rules::credit_card::is_valid("1234"));
+/// ```
+pub fn is_valid(v: &str) -> bool {
+    apply(&v, ()).is_ok()
+}
+
+/// A card network, as detected by [`detect_network`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CardNetwork {
+    VisaElectron,
+    Maestro,
+    Forbrugsforeningen,
+    Dankort,
+    Visa,
+    Mir,
+    Mastercard,
+    Amex,
+    DinersClub,
+    Discover,
+    UnionPay,
+    Jcb,
+}
+
+/// Returns the network of `v`, or `None` if `v` is not a valid credit card number.
+///
+/// The network is detected from the leading digits of the number (its IIN range), which are checked in this order:
+///
+/// | Network            | Prefix                                                   | Length   |
+/// |--------------------|----------------------------------------------------------|----------|
+/// | Visa Electron      | `4026`, `417500`, `4405`, `4508`, `4844`, `4913`, `4917` | 16       |
+/// | Maestro            | `5018`, `502`, `503`, `56`, `58`, `639`, `67`            | 12 to 19 |
+/// | Forbrugsforeningen | `600`                                                    | 16       |
+/// | Dankort            | `5019`                                                   | 16       |
+/// | Visa               | `4`                                                      | 13 to 16 |
+/// | Mir                | `2200` to `2204`                                         | 16 to 19 |
+/// | Mastercard         | `51` to `55`, `22` to `27`                               | 16       |
+/// | Amex               | `34`, `37`                                               | 15       |
+/// | Diners Club        | `30`, `36`, `38`, `39`                                   | 14       |
+/// | Discover           | `60`, `64`, `65`, `622`                                  | 16       |
+/// | UnionPay           | `62`, `88`                                               | 16 to 19 |
+/// | JCB                | `35`                                                     | 16       |
+///
+/// Like the `#[garde(credit_card)]` rule, the number must only contain digits, have a valid length for its network,
+/// and pass the Luhn checksum.
+///
+/// ```rust
+/// use garde::rules::credit_card::{detect_network, CardNetwork};
+///
+/// assert_eq!(detect_network("4539571147647251"), Some(CardNetwork::Visa));
+/// assert_eq!(detect_network("5555555555554444"), Some(CardNetwork::Mastercard));
+/// assert_eq!(detect_network("378282246310005"), Some(CardNetwork::Amex));
+/// assert_eq!(detect_network("6011111111111117"), Some(CardNetwork::Discover));
+/// assert_eq!(detect_network("4539571147647252"), None);
+/// ```
+pub fn detect_network(v: &str) -> Option<CardNetwork> {
+    let card = card_validate::Validate::from(v).ok()?;
+    let network = match card.card_type {
+        card_validate::Type::VisaElectron => CardNetwork::VisaElectron,
+        card_validate::Type::Maestro => CardNetwork::Maestro,
+        card_validate::Type::Forbrugsforeningen => CardNetwork::Forbrugsforeningen,
+        card_validate::Type::Dankort => CardNetwork::Dankort,
+        card_validate::Type::Visa => CardNetwork::Visa,
+        card_validate::Type::MIR => CardNetwork::Mir,
+        card_validate::Type::MasterCard => CardNetwork::Mastercard,
+        card_validate::Type::Amex => CardNetwork::Amex,
+        card_validate::Type::DinersClub => CardNetwork::DinersClub,
+        card_validate::Type::Discover => CardNetwork::Discover,
+        card_validate::Type::UnionPay => CardNetwork::UnionPay,
+        card_validate::Type::JCB => CardNetwork::Jcb,
+        _ => return None,
+    };
+    Some(network)
+}
+
 pub trait CreditCard {
     type Error: Display;
 
@@ -0,0 +1,53 @@
+//! Per-character validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(each_char(|c: char| c.is_ascii_lowercase() || c == '-'))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The predicate is any function or closure of type `Fn(char) -> bool`, and the rule fails on the first character
+//! for which it returns `false`. The error contains that character and its index, which counts characters, not bytes.
+//!
+//! The entrypoint is the [`EachChar`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(each_char)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use alloc::format;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: EachChar, F: Fn(char) -> bool>(v: &T, (predicate,): (&F,)) -> Result<(), Error> {
+    if let Some((index, c)) = v.validate_each_char(predicate) {
+        return Err(Error::new(format!(
+            "invalid character {c:?} at index {index}"
+        )));
+    }
+    Ok(())
+}
+
+pub trait EachChar {
+    /// Returns the index and value of the first character for which `predicate` returns `false`.
+    fn validate_each_char(&self, predicate: &dyn Fn(char) -> bool) -> Option<(usize, char)>;
+}
+
+impl<T: AsStr> EachChar for T {
+    fn validate_each_char(&self, predicate: &dyn Fn(char) -> bool) -> Option<(usize, char)> {
+        self.as_str()
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !predicate(*c))
+    }
+}
+
+impl<T: EachChar> EachChar for Option<T> {
+    fn validate_each_char(&self, predicate: &dyn Fn(char) -> bool) -> Option<(usize, char)> {
+        match self {
+            Some(value) => value.validate_each_char(predicate),
+            None => None,
+        }
+    }
+}
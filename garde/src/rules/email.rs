@@ -38,6 +38,59 @@ pub fn apply<T: Email>(v: &T, _: ()) -> Result<(), Error> {
     Ok(())
 }
 
+/// Returns `true` if `v` is a valid email address.
+///
+/// This is the standalone equivalent of the `#[garde(email)]` rule.
+///
+/// ```rust
+/// assert!(garde::rules::email::is_valid("test@mail.com"));
+/// assert!(!garde::rules::email::is_valid("test"));
+/// ```
+pub fn is_valid(v: &str) -> bool {
+    apply(&v, ()).is_ok()
+}
+
+/// Normalizes a valid email address, so that addresses which reach the same mailbox compare equal.
+///
+/// This is meant for detecting duplicate accounts, and is separate from validation.
+/// Returns `None` if `v` is not a valid email address, see [`is_valid`].
+///
+/// The address is normalized as follows:
+/// - the domain is lowercased
+/// - a `+tag` suffix is removed from the user, unless nothing would be left of it
+///
+/// If `gmail` is `true`, addresses at `gmail.com` and `googlemail.com` are additionally normalized
+/// the way Gmail treats them:
+/// - the domain is replaced with `gmail.com`
+/// - the user is lowercased
+/// - dots are removed from the user
+///
+/// The user is otherwise kept as-is, as it may be case-sensitive on other providers.
+///
+/// ```rust
+/// use garde::rules::email::normalize;
+///
+/// assert_eq!(normalize("Jane.Doe+news@Example.COM", false).as_deref(), Some("Jane.Doe@example.com"));
+/// assert_eq!(normalize("Jane.Doe+news@GoogleMail.com", true).as_deref(), Some("janedoe@gmail.com"));
+/// assert_eq!(normalize("not an email", false), None);
+/// ```
+pub fn normalize(v: &str, gmail: bool) -> Option<String> {
+    parse_email(v).ok()?;
+    let (user, domain) = v.split_once('@')?;
+    let domain = domain.to_lowercase();
+    let user = match user.split_once('+') {
+        Some((untagged, _)) if !untagged.is_empty() => untagged,
+        _ => user,
+    };
+
+    if gmail && (domain == "gmail.com" || domain == "googlemail.com") {
+        let user = user.replace('.', "").to_lowercase();
+        return Some(format!("{user}@gmail.com"));
+    }
+
+    Some(format!("{user}@{domain}"))
+}
+
 pub trait Email {
     type Error: Display;
 
@@ -267,6 +320,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_normalize() {
+        let tests = &[
+            ("user@example.com", false, Some("user@example.com")),
+            ("User@EXAMPLE.com", false, Some("User@example.com")),
+            ("user+tag@example.com", false, Some("user@example.com")),
+            ("user+a+b@example.com", false, Some("user@example.com")),
+            ("+tag@example.com", false, Some("+tag@example.com")),
+            ("first.last@gmail.com", false, Some("first.last@gmail.com")),
+            (
+                "First.Last+tag@GMail.com",
+                true,
+                Some("firstlast@gmail.com"),
+            ),
+            (
+                "first.last@googlemail.com",
+                true,
+                Some("firstlast@gmail.com"),
+            ),
+            (
+                "first.last@example.com",
+                true,
+                Some("first.last@example.com"),
+            ),
+            ("", false, None),
+            ("user", true, None),
+            ("user@-example.com", false, None),
+        ];
+
+        for (input, gmail, expected) in tests {
+            assert_eq!(
+                normalize(input, *gmail).as_deref(),
+                *expected,
+                "normalize({input:?}, {gmail})"
+            );
+        }
+    }
+
     #[test]
     fn test_parse_email_cow() {
         let test: Cow<'static, str> = "email@here.com".into();
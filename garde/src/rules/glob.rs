@@ -0,0 +1,89 @@
+//! Glob pattern validation, which doesn't require the `regex` feature.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(glob("*.txt"))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! In the pattern, `*` matches any sequence of characters (including an empty one), and `?` matches exactly one character.
+//! Every other character only matches itself. There is no escaping and there are no character classes,
+//! and unlike file system globs, `*` also matches `/`. For anything more complex, use the `pattern` rule.
+//!
+//! The entrypoint is the [`Glob`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(glob)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use alloc::format;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Glob>(v: &T, (pattern,): (&str,)) -> Result<(), Error> {
+    if !v.validate_glob(pattern) {
+        return Err(Error::new(format!("must match pattern {pattern}")));
+    }
+    Ok(())
+}
+
+/// Returns `true` if `value` matches the glob `pattern`.
+///
+/// ```rust
+/// assert!(garde::rules::glob::is_match("*.txt", "notes.txt"));
+/// assert!(garde::rules::glob::is_match("v?.*", "v1.2"));
+/// assert!(!garde::rules::glob::is_match("*.txt", "notes.md"));
+/// ```
+pub fn is_match(pattern: &str, value: &str) -> bool {
+    let (mut pattern_rest, mut value_rest) = (pattern, value);
+    // Where to resume after the last `*`, if the rest of the pattern doesn't match.
+    let mut backtrack = None;
+    loop {
+        let mut pattern_chars = pattern_rest.chars();
+        let mut value_chars = value_rest.chars();
+        match (pattern_chars.next(), value_chars.next()) {
+            (Some('*'), _) => {
+                pattern_rest = pattern_chars.as_str();
+                backtrack = Some((pattern_rest, value_rest));
+            }
+            (Some(p), Some(v)) if p == '?' || p == v => {
+                pattern_rest = pattern_chars.as_str();
+                value_rest = value_chars.as_str();
+            }
+            (None, None) => return true,
+            _ => {
+                // Let the last `*` consume one more character, and try again.
+                let Some((after_star, consumed)) = backtrack else {
+                    return false;
+                };
+                let mut consumed = consumed.chars();
+                if consumed.next().is_none() {
+                    return false;
+                }
+                pattern_rest = after_star;
+                value_rest = consumed.as_str();
+                backtrack = Some((pattern_rest, value_rest));
+            }
+        }
+    }
+}
+
+pub trait Glob {
+    fn validate_glob(&self, pattern: &str) -> bool;
+}
+
+impl<T: AsStr> Glob for T {
+    fn validate_glob(&self, pattern: &str) -> bool {
+        is_match(pattern, self.as_str())
+    }
+}
+
+impl<T: Glob> Glob for Option<T> {
+    fn validate_glob(&self, pattern: &str) -> bool {
+        match self {
+            Some(value) => value.validate_glob(pattern),
+            None => true,
+        }
+    }
+}
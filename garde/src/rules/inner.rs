@@ -10,6 +10,8 @@
 //!
 //! The entrypoint is the [`Inner`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(inner(..))]` rule.
 
+use alloc::vec::Vec;
+
 use crate::error::{NoKey, PathComponentKind};
 
 pub fn apply<T, U, K, F>(field: &T, f: F)
@@ -39,6 +41,30 @@ impl<T> Inner<T> for Vec<T> {
     }
 }
 
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> Inner<A::Item> for smallvec::SmallVec<A> {
+    type Key = usize;
+
+    fn validate_inner<F>(&self, f: F)
+    where
+        F: FnMut(&A::Item, &Self::Key),
+    {
+        self.as_slice().validate_inner(f)
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> Inner<T> for arrayvec::ArrayVec<T, CAP> {
+    type Key = usize;
+
+    fn validate_inner<F>(&self, f: F)
+    where
+        F: FnMut(&T, &Self::Key),
+    {
+        self.as_slice().validate_inner(f)
+    }
+}
+
 impl<const N: usize, T> Inner<T> for [T; N] {
     type Key = usize;
 
@@ -75,3 +101,17 @@ impl<T> Inner<T> for Option<T> {
         }
     }
 }
+
+/// Only the `Ok` variant is validated, at the key `ok`.
+impl<T, E> Inner<T> for Result<T, E> {
+    type Key = &'static str;
+
+    fn validate_inner<F>(&self, mut f: F)
+    where
+        F: FnMut(&T, &Self::Key),
+    {
+        if let Ok(item) = self {
+            f(item, &"ok")
+        }
+    }
+}
@@ -24,6 +24,20 @@ pub fn apply<T: Ip>(v: &T, (kind,): (IpKind,)) -> Result<(), Error> {
     Ok(())
 }
 
+/// Returns `true` if `v` is a valid IP address of the given `kind`.
+///
+/// This is the standalone equivalent of the `#[garde(ip)]`, `#[garde(ipv4)]` and `#[garde(ipv6)]` rules.
+///
+/// ```rust
+/// use garde::rules::ip::{is_valid, IpKind};
+///
+/// assert!(is_valid("127.0.0.1", IpKind::Any));
+/// assert!(!is_valid("127.0.0.1", IpKind::V6));
+/// ```
+pub fn is_valid(v: &str, kind: IpKind) -> bool {
+    apply(&v, (kind,)).is_ok()
+}
+
 pub trait Ip {
     type Error: Display;
 
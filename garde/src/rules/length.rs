@@ -59,10 +59,14 @@ pub use simple::HasSimpleLength;
 pub mod utf16;
 pub use utf16::HasUtf16CodeUnits;
 
+use alloc::format;
+
 use crate::error::Error;
 
 fn check_len(len: usize, min: usize, max: usize) -> Result<(), Error> {
-    if len < min {
+    if min == max && len != min {
+        Err(Error::new(format!("length must be exactly {min}")))
+    } else if len < min {
         Err(Error::new(format!("length is lower than {min}")))
     } else if len > max {
         Err(Error::new(format!("length is greater than {max}")))
@@ -1,6 +1,12 @@
 //! Implemented by types for which we can retrieve the number of bytes.
 //!
 //! See also: [`chars` on `str`](https://doc.rust-lang.org/std/primitive.str.html#method.chars).
+//!
+//! With the `std` feature, this is also implemented for `OsStr`, `OsString`, `Path` and `PathBuf`,
+//! which count the bytes of their platform-specific encoding, see [`OsStr::len`][std::ffi::OsStr::len].
+//! On Unix, this is the number of bytes in the path as passed to the OS.
+//!
+//! With the `bytes` feature, this is also implemented for `bytes::Bytes` and `bytes::BytesMut`.
 
 use crate::error::Error;
 
@@ -41,18 +47,47 @@ macro_rules! impl_via_len {
     };
 }
 
-impl_via_len!(std::string::String);
-impl_via_len!(in<'a> &'a std::string::String);
+impl_via_len!(alloc::string::String);
+impl_via_len!(in<'a> &'a alloc::string::String);
 impl_via_len!(in<'a> &'a str);
-impl_via_len!(in<'a> std::borrow::Cow<'a, str>);
-impl_via_len!(std::rc::Rc<str>);
-impl_via_len!(std::sync::Arc<str>);
-impl_via_len!(std::boxed::Box<str>);
+impl_via_len!(in<'a> alloc::borrow::Cow<'a, str>);
+impl_via_len!(alloc::rc::Rc<str>);
+impl_via_len!(alloc::sync::Arc<str>);
+impl_via_len!(alloc::boxed::Box<str>);
 impl_via_len!(in<'a> &'a [u8]);
-impl_via_len!(std::rc::Rc<[u8]>);
-impl_via_len!(std::sync::Arc<[u8]>);
-impl_via_len!(std::boxed::Box<[u8]>);
-impl_via_len!(std::vec::Vec<u8>);
+impl_via_len!(alloc::rc::Rc<[u8]>);
+impl_via_len!(alloc::sync::Arc<[u8]>);
+impl_via_len!(alloc::boxed::Box<[u8]>);
+impl_via_len!(alloc::vec::Vec<u8>);
+#[cfg(feature = "bytes")]
+impl_via_len!(bytes::Bytes);
+#[cfg(feature = "bytes")]
+impl_via_len!(bytes::BytesMut);
+
+#[cfg(feature = "std")]
+impl_via_len!(std::ffi::OsString);
+#[cfg(feature = "std")]
+impl_via_len!(in<'a> &'a std::ffi::OsStr);
+#[cfg(feature = "std")]
+impl_via_len!(alloc::boxed::Box<std::ffi::OsStr>);
+
+#[cfg(feature = "std")]
+macro_rules! impl_via_os_str {
+    ($(in<$lifetime:lifetime>)? $T:ty) => {
+        impl<$($lifetime)?> HasBytes for $T {
+            fn num_bytes(&self) -> usize {
+                self.as_os_str().len()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+impl_via_os_str!(std::path::PathBuf);
+#[cfg(feature = "std")]
+impl_via_os_str!(in<'a> &'a std::path::Path);
+#[cfg(feature = "std")]
+impl_via_os_str!(alloc::boxed::Box<std::path::Path>);
 
 impl<const N: usize> HasBytes for [u8; N] {
     fn num_bytes(&self) -> usize {
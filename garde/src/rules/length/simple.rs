@@ -2,6 +2,10 @@
 //!
 //! The meaning of "length" depends on the type.
 //! For example, the length of a `String` is defined as the number of _bytes_ it stores.
+//! The same goes for `OsString` and `PathBuf`, which store bytes in a platform-specific encoding.
+//! For byte buffers such as `Vec<u8>`, `&[u8]` and, with the `bytes` feature, `bytes::Bytes`, it is the number of bytes.
+
+use alloc::vec::Vec;
 
 use crate::error::Error;
 
@@ -43,13 +47,25 @@ macro_rules! impl_via_bytes {
     };
 }
 
-impl_via_bytes!(std::string::String);
-impl_via_bytes!(in<'a> &'a std::string::String);
+impl_via_bytes!(alloc::string::String);
+impl_via_bytes!(in<'a> &'a alloc::string::String);
 impl_via_bytes!(in<'a> &'a str);
-impl_via_bytes!(in<'a> std::borrow::Cow<'a, str>);
-impl_via_bytes!(std::rc::Rc<str>);
-impl_via_bytes!(std::sync::Arc<str>);
-impl_via_bytes!(std::boxed::Box<str>);
+impl_via_bytes!(in<'a> alloc::borrow::Cow<'a, str>);
+impl_via_bytes!(alloc::rc::Rc<str>);
+impl_via_bytes!(alloc::sync::Arc<str>);
+impl_via_bytes!(alloc::boxed::Box<str>);
+#[cfg(feature = "std")]
+impl_via_bytes!(std::ffi::OsString);
+#[cfg(feature = "std")]
+impl_via_bytes!(in<'a> &'a std::ffi::OsStr);
+#[cfg(feature = "std")]
+impl_via_bytes!(alloc::boxed::Box<std::ffi::OsStr>);
+#[cfg(feature = "std")]
+impl_via_bytes!(std::path::PathBuf);
+#[cfg(feature = "std")]
+impl_via_bytes!(in<'a> &'a std::path::Path);
+#[cfg(feature = "std")]
+impl_via_bytes!(alloc::boxed::Box<std::path::Path>);
 
 macro_rules! impl_via_len {
     (in<$lifetime:lifetime, $($generic:ident),*> $T:ty) => {
@@ -85,6 +101,9 @@ macro_rules! impl_via_len {
 impl_via_len!(in<T> Vec<T>);
 impl_via_len!(in<'a, T> &'a Vec<T>);
 impl_via_len!(in<'a, T> &'a [T]);
+impl_via_len!(in<T> alloc::boxed::Box<[T]>);
+impl_via_len!(in<T> alloc::rc::Rc<[T]>);
+impl_via_len!(in<T> alloc::sync::Arc<[T]>);
 
 impl<const N: usize, T> Simple for [T; N] {
     fn validate_length(&self, min: usize, max: usize) -> Result<(), Error> {
@@ -98,17 +117,67 @@ impl<'a, const N: usize, T> Simple for &'a [T; N] {
     }
 }
 
+#[cfg(feature = "std")]
 impl_via_len!(in<K, V, S> std::collections::HashMap<K, V, S>);
+#[cfg(feature = "std")]
 impl_via_len!(in<T, S> std::collections::HashSet<T, S>);
-impl_via_len!(in<K, V> std::collections::BTreeMap<K, V>);
-impl_via_len!(in<T> std::collections::BTreeSet<T>);
-impl_via_len!(in<T> std::collections::VecDeque<T>);
-impl_via_len!(in<T> std::collections::BinaryHeap<T>);
-impl_via_len!(in<T> std::collections::LinkedList<T>);
+impl_via_len!(in<K, V> alloc::collections::BTreeMap<K, V>);
+impl_via_len!(in<T> alloc::collections::BTreeSet<T>);
+impl_via_len!(in<T> alloc::collections::VecDeque<T>);
+impl_via_len!(in<T> alloc::collections::BinaryHeap<T>);
+impl_via_len!(in<T> alloc::collections::LinkedList<T>);
+#[cfg(feature = "std")]
 impl_via_len!(in<'a, K, V, S> &'a std::collections::HashMap<K, V, S>);
+#[cfg(feature = "std")]
 impl_via_len!(in<'a, T, S> &'a std::collections::HashSet<T, S>);
-impl_via_len!(in<'a, K, V> &'a std::collections::BTreeMap<K, V>);
-impl_via_len!(in<'a, T> &'a std::collections::BTreeSet<T>);
-impl_via_len!(in<'a, T> &'a std::collections::VecDeque<T>);
-impl_via_len!(in<'a, T> &'a std::collections::BinaryHeap<T>);
-impl_via_len!(in<'a, T> &'a std::collections::LinkedList<T>);
+impl_via_len!(in<'a, K, V> &'a alloc::collections::BTreeMap<K, V>);
+impl_via_len!(in<'a, T> &'a alloc::collections::BTreeSet<T>);
+impl_via_len!(in<'a, T> &'a alloc::collections::VecDeque<T>);
+impl_via_len!(in<'a, T> &'a alloc::collections::BinaryHeap<T>);
+impl_via_len!(in<'a, T> &'a alloc::collections::LinkedList<T>);
+
+#[cfg(feature = "bytes")]
+impl_via_len!(bytes::Bytes);
+#[cfg(feature = "bytes")]
+impl_via_len!(bytes::BytesMut);
+#[cfg(feature = "bytes")]
+impl_via_len!(in<'a> &'a bytes::Bytes);
+#[cfg(feature = "bytes")]
+impl_via_len!(in<'a> &'a bytes::BytesMut);
+
+#[cfg(feature = "indexmap")]
+impl_via_len!(in<K, V, S> indexmap::IndexMap<K, V, S>);
+#[cfg(feature = "indexmap")]
+impl_via_len!(in<T, S> indexmap::IndexSet<T, S>);
+#[cfg(feature = "indexmap")]
+impl_via_len!(in<'a, K, V, S> &'a indexmap::IndexMap<K, V, S>);
+#[cfg(feature = "indexmap")]
+impl_via_len!(in<'a, T, S> &'a indexmap::IndexSet<T, S>);
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> HasSimpleLength for smallvec::SmallVec<A> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> HasSimpleLength for &smallvec::SmallVec<A> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> HasSimpleLength for arrayvec::ArrayVec<T, CAP> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> HasSimpleLength for &arrayvec::ArrayVec<T, CAP> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
@@ -2,25 +2,44 @@
 
 pub mod alphanumeric;
 pub mod ascii;
+#[cfg(feature = "byte-size")]
+pub mod byte_size;
+pub mod charset;
+#[cfg(feature = "std")]
+pub mod cidr;
 pub mod contains;
+pub mod contains_all;
+pub mod contains_any;
+pub mod contains_item;
 #[cfg(feature = "credit-card")]
 pub mod credit_card;
+pub mod each_char;
 #[cfg(feature = "email")]
 pub mod email;
+pub mod glob;
 pub mod inner;
+#[cfg(feature = "std")]
 pub mod ip;
 pub mod length;
 pub mod matches;
+pub mod not_pattern;
+pub mod path;
 pub mod pattern;
+#[cfg(feature = "regex")]
+pub mod pattern_captures;
 #[cfg(feature = "phone-number")]
 pub mod phone_number;
 pub mod prefix;
+pub mod prefix_any;
 pub mod range;
 pub mod required;
 pub mod suffix;
+pub mod suffix_any;
 #[cfg(feature = "url")]
 pub mod url;
 
+use alloc::string::String;
+
 pub trait AsStr {
     fn as_str(&self) -> &str;
 }
@@ -37,8 +56,38 @@ impl AsStr for String {
     }
 }
 
-impl<'a> AsStr for std::borrow::Cow<'a, str> {
+impl<'a> AsStr for alloc::borrow::Cow<'a, str> {
+    fn as_str(&self) -> &str {
+        alloc::borrow::Cow::as_ref(self)
+    }
+}
+
+impl AsStr for alloc::boxed::Box<str> {
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+impl AsStr for alloc::rc::Rc<str> {
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+impl AsStr for alloc::sync::Arc<str> {
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+impl<T: AsStr> AsStr for alloc::rc::Rc<T> {
+    fn as_str(&self) -> &str {
+        T::as_str(self)
+    }
+}
+
+impl<T: AsStr> AsStr for alloc::sync::Arc<T> {
     fn as_str(&self) -> &str {
-        std::borrow::Cow::as_ref(self)
+        T::as_str(self)
     }
 }
@@ -0,0 +1,52 @@
+//! Negated pattern validation.
+//!
+//! The value must *not* match the pattern. The pattern argument is the same as for the [`pattern`][`super::pattern`] rule,
+//! so it can be a regular expression provided as a string literal (if the `regex` feature is enabled), or an expression
+//! of type implementing [`Matcher`].
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(not_pattern(r"(?i)drop\s+table"))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`NotPattern`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(not_pattern(...))]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use alloc::format;
+
+use super::pattern::Matcher;
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: NotPattern, M: Matcher>(v: &T, (pat,): (&M,)) -> Result<(), Error> {
+    if !v.validate_not_pattern(pat) {
+        return Err(Error::new(format!(
+            "must not match pattern /{}/",
+            pat.as_str()
+        )));
+    }
+    Ok(())
+}
+
+pub trait NotPattern {
+    fn validate_not_pattern<M: Matcher>(&self, matcher: &M) -> bool;
+}
+
+impl<T: AsStr> NotPattern for T {
+    fn validate_not_pattern<M: Matcher>(&self, matcher: &M) -> bool {
+        !matcher.is_match(self.as_str())
+    }
+}
+
+impl<T: NotPattern> NotPattern for Option<T> {
+    fn validate_not_pattern<M: Matcher>(&self, matcher: &M) -> bool {
+        match self {
+            Some(value) => value.validate_not_pattern(matcher),
+            None => true,
+        }
+    }
+}
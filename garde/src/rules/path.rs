@@ -0,0 +1,65 @@
+//! Path component validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(path_component)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! This is meant for user-supplied file names, which are joined onto a trusted directory.
+//! A safe path component:
+//! - is not empty
+//! - is not `.` or `..`
+//! - does not contain a path separator, which is either `/` or `\` regardless of the platform
+//! - does not contain a NUL character
+//!
+//! This also rejects absolute paths, as they always contain a separator, but it does not reject names
+//! which are reserved on some platforms, such as `CON` on Windows.
+//!
+//! The entrypoint is the [`SafePathComponent`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(path_component)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: SafePathComponent>(v: &T, _: ()) -> Result<(), Error> {
+    if !v.is_safe_path_component() {
+        return Err(Error::new("not a safe filename"));
+    }
+    Ok(())
+}
+
+/// Returns `true` if `v` is a single safe path component.
+///
+/// This is the standalone equivalent of the `#[garde(path_component)]` rule.
+///
+/// ```rust
+/// assert!(garde::rules::path::is_valid("report.pdf"));
+/// assert!(!garde::rules::path::is_valid("../etc/passwd"));
+/// ```
+pub fn is_valid(v: &str) -> bool {
+    apply(&v, ()).is_ok()
+}
+
+pub trait SafePathComponent {
+    fn is_safe_path_component(&self) -> bool;
+}
+
+impl<T: AsStr> SafePathComponent for T {
+    fn is_safe_path_component(&self) -> bool {
+        let v = self.as_str();
+        !v.is_empty() && v != "." && v != ".." && !v.contains(['/', '\\', '\0'])
+    }
+}
+
+impl<T: SafePathComponent> SafePathComponent for Option<T> {
+    fn is_safe_path_component(&self) -> bool {
+        match self {
+            Some(value) => value.is_safe_path_component(),
+            None => true,
+        }
+    }
+}
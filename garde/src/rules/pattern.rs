@@ -28,15 +28,51 @@
 //! }
 //! ```
 //!
+//! By default, the whole value is matched against the pattern, however long it is. When validating untrusted input,
+//! `max_len` may be used to reject any value longer than the given number of bytes before the pattern runs at all,
+//! which bounds the time spent matching it. This is opt-in, there is no limit unless `max_len` is given:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(pattern(r"^[a-z]+(-[a-z]+)*$", max_len = 256))]
+//!     v: String,
+//! }
+//! ```
+//!
 //! The entrypoint is the [`Pattern`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(pattern(...))]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
 
+use alloc::format;
+use core::cell::Cell;
+
 use super::AsStr;
 use crate::error::Error;
 
-pub fn apply<T: Pattern, M: Matcher>(v: &T, (pat,): (&M,)) -> Result<(), Error> {
-    if !v.validate_pattern(pat) {
+pub fn apply<T: Pattern, M: Matcher>(
+    v: &T,
+    (pat, max_len): (&M, Option<usize>),
+) -> Result<(), Error> {
+    let is_match = match max_len {
+        Some(max_len) => {
+            let limited = MaxLen {
+                matcher: pat,
+                max_len,
+                exceeded: Cell::new(false),
+            };
+            let is_match = v.validate_pattern(&limited);
+            if limited.exceeded.get() {
+                return Err(Error::new(format!(
+                    "length is greater than {max_len}, not matched against pattern /{}/",
+                    pat.as_str()
+                )));
+            }
+            is_match
+        }
+        None => v.validate_pattern(pat),
+    };
+    if !is_match {
         return Err(Error::new(format!(
             "does not match pattern /{}/",
             pat.as_str()
@@ -45,6 +81,29 @@ pub fn apply<T: Pattern, M: Matcher>(v: &T, (pat,): (&M,)) -> Result<(), Error>
     Ok(())
 }
 
+/// Rejects any haystack longer than `max_len` bytes without running `matcher` on it.
+struct MaxLen<'a, M> {
+    matcher: &'a M,
+    max_len: usize,
+    exceeded: Cell<bool>,
+}
+
+impl<M: Matcher> AsStr for MaxLen<'_, M> {
+    fn as_str(&self) -> &str {
+        self.matcher.as_str()
+    }
+}
+
+impl<M: Matcher> Matcher for MaxLen<'_, M> {
+    fn is_match(&self, haystack: &str) -> bool {
+        if haystack.len() > self.max_len {
+            self.exceeded.set(true);
+            return false;
+        }
+        self.matcher.is_match(haystack)
+    }
+}
+
 pub trait Matcher: AsStr {
     /// Returns true if and only if there is a match for the pattern anywhere in the haystack given.
     fn is_match(&self, haystack: &str) -> bool;
@@ -0,0 +1,100 @@
+//! Pattern validation with access to the captured groups.
+//!
+//! ```rust
+//! use garde::rules::pattern_captures::Captures;
+//!
+//! fn check_year(captures: &Captures, _: &()) -> garde::Result {
+//!     match captures["year"].parse::<u32>() {
+//!         Ok(1900..=2100) => Ok(()),
+//!         _ => Err(garde::Error::new("year is out of range")),
+//!     }
+//! }
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(pattern_captures(r"^(?P<year>\d{4})-\d{2}$", check_year))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! If the value does not match the pattern, the rule fails with the same error as `pattern`.
+//! Otherwise, the captures of the first match are passed to the function along with the context,
+//! and the rule fails with the error it returns, if any.
+//!
+//! Like `pattern`, the pattern may also be an expression of a type implementing [`CaptureMatcher`],
+//! which is implemented for `regex::Regex` and `once_cell::sync::Lazy<T>` with any `T: CaptureMatcher`.
+//! This rule is not supported with the `js-sys` feature.
+//!
+//! The entrypoint is the [`PatternCaptures`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(pattern_captures(...))]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use alloc::format;
+
+pub use ::regex::Captures;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T, M, F, C>(v: &T, (matcher, check, ctx): (&M, &F, &C)) -> Result<(), Error>
+where
+    T: PatternCaptures,
+    M: CaptureMatcher,
+    F: Fn(&Captures<'_>, &C) -> Result<(), Error>,
+    C: ?Sized,
+{
+    v.validate_pattern_captures(matcher, |captures| check(captures, ctx))
+}
+
+pub trait CaptureMatcher: AsStr {
+    /// Returns the capture groups of the leftmost match in the haystack, if there is one.
+    fn captures<'h>(&self, haystack: &'h str) -> Option<Captures<'h>>;
+}
+
+impl CaptureMatcher for ::regex::Regex {
+    fn captures<'h>(&self, haystack: &'h str) -> Option<Captures<'h>> {
+        self.captures(haystack)
+    }
+}
+
+impl<T: CaptureMatcher> CaptureMatcher for once_cell::sync::Lazy<T> {
+    fn captures<'h>(&self, haystack: &'h str) -> Option<Captures<'h>> {
+        once_cell::sync::Lazy::force(self).captures(haystack)
+    }
+}
+
+pub trait PatternCaptures {
+    fn validate_pattern_captures<M, F>(&self, matcher: &M, check: F) -> Result<(), Error>
+    where
+        M: CaptureMatcher,
+        F: FnOnce(&Captures<'_>) -> Result<(), Error>;
+}
+
+impl<T: AsStr> PatternCaptures for T {
+    fn validate_pattern_captures<M, F>(&self, matcher: &M, check: F) -> Result<(), Error>
+    where
+        M: CaptureMatcher,
+        F: FnOnce(&Captures<'_>) -> Result<(), Error>,
+    {
+        match matcher.captures(self.as_str()) {
+            Some(captures) => check(&captures),
+            None => Err(Error::new(format!(
+                "does not match pattern /{}/",
+                matcher.as_str()
+            ))),
+        }
+    }
+}
+
+impl<T: PatternCaptures> PatternCaptures for Option<T> {
+    fn validate_pattern_captures<M, F>(&self, matcher: &M, check: F) -> Result<(), Error>
+    where
+        M: CaptureMatcher,
+        F: FnOnce(&Captures<'_>) -> Result<(), Error>,
+    {
+        match self {
+            Some(value) => value.validate_pattern_captures(matcher, check),
+            None => Ok(()),
+        }
+    }
+}
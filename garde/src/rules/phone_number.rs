@@ -11,6 +11,15 @@
 //! The entrypoint is the [`PhoneNumber`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(phone_number)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+//!
+//! Validation does not modify the value. To obtain the normalized [E.164](https://en.wikipedia.org/wiki/E.164)
+//! form of a phone number, e.g. before storing it, call [`normalize`] as a separate step:
+//!
+//! ```rust
+//! use garde::rules::phone_number::normalize;
+//!
+//! assert_eq!(normalize("+1 (415) 237-0800", None).as_deref(), Some("+14152370800"));
+//! ```
 
 use std::fmt::Display;
 use std::str::FromStr;
@@ -26,6 +35,41 @@ pub fn apply<T: PhoneNumber>(v: &T, _: ()) -> Result<(), Error> {
     }
 }
 
+/// Returns `true` if `v` is a valid phone number.
+///
+/// This is the standalone equivalent of the `#[garde(phone_number)]` rule.
+///
+/// ```rust
+/// assert!(garde::rules::phone_number::is_valid("+14152370800"));
+/// assert!(!garde::rules::phone_number::is_valid("14152370800"));
+/// ```
+pub fn is_valid(v: &str) -> bool {
+    apply(&v, ()).is_ok()
+}
+
+/// Parses `s` as a phone number and returns it in [E.164](https://en.wikipedia.org/wiki/E.164) format.
+///
+/// `region` is used to interpret numbers written without an international prefix.
+/// If it is `None`, the number must start with `+` and a country calling code.
+///
+/// Returns `None` if `s` is not a valid phone number.
+///
+/// ```rust
+/// use garde::rules::phone_number::{normalize, Region};
+///
+/// assert_eq!(normalize("(415) 237-0800", Some(Region::US)).as_deref(), Some("+14152370800"));
+/// assert_eq!(normalize("(415) 237-0800", None), None);
+/// ```
+pub fn normalize(s: &str, region: Option<Region>) -> Option<String> {
+    let number = phonenumber::parse(region, s).ok()?;
+    if !number.is_valid() {
+        return None;
+    }
+    Some(number.format().mode(phonenumber::Mode::E164).to_string())
+}
+
+pub use phonenumber::country::Id as Region;
+
 pub trait PhoneNumber {
     type Error: Display;
 
@@ -0,0 +1,28 @@
+//! Prefix set validation, requiring at least one of the prefixes.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(prefix_any(["+1", "+44"]))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The prefixes must be non-empty string literals, and there must be at least one of them.
+//!
+//! This rule uses the [`Prefix`] trait, so it may be used with any type which supports the `#[garde(prefix)]` rule.
+
+use alloc::format;
+
+use super::prefix::Prefix;
+use crate::error::Error;
+
+pub fn apply<T: Prefix>(v: &T, (pats,): (&[&str],)) -> Result<(), Error> {
+    if !pats.iter().any(|pat| v.validate_prefix(pat)) {
+        return Err(Error::new(format!(
+            "must start with one of: {}",
+            pats.join(", ")
+        )));
+    }
+    Ok(())
+}
@@ -8,11 +8,76 @@
 //! }
 //! ```
 //!
+//! The bounds may be any expression, including one which refers to another field of the struct through `self`:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(skip)]
+//!     min_price: u64,
+//!     #[garde(range(min=self.min_price))]
+//!     max_price: u64,
+//! }
+//! ```
+//!
+//! In enum variants, `self` is the enum itself, so fields can't be accessed through it.
+//!
+//! If the value doesn't implement [`Bounds`] itself, a `key` may be used to project it into one that does.
+//! The key is a function (or closure) receiving a reference to the field, and the bounds are applied to its output:
+//!
+//! ```rust
+//! struct Celsius(i32);
+//!
+//! impl Celsius {
+//!     fn degrees(&self) -> i32 {
+//!         self.0
+//!     }
+//! }
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(range(min=-10, max=40, key=Celsius::degrees))]
+//!     v: Celsius,
+//! }
+//! ```
+//!
 //! The entrypoint is the [`Bounds`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(range(...))]` rule.
 //!
-//! This trait is implemented for all primitive integer types.
+//! This trait is implemented for all primitive integer and floating point types, `char`, and for the `NonZero` integer types,
+//! whose bounds are written as the underlying integer (e.g. `range(max = 10)` for a `NonZeroU8`).
+//! It is also implemented for `Box<T>`, `Rc<T>` and `Arc<T>` whenever `T` implements it, using the bounds of `T`.
+//! With the `time` feature, it is also implemented for [`time::Date`], [`time::Time`], [`time::PrimitiveDateTime`],
+//! and [`time::OffsetDateTime`], which makes it possible to check that a date or time falls within a range.
+//! An `OffsetDateTime` is compared as a point in time, regardless of its offset:
+//!
+//! ```rust,ignore
+//! use time::macros::date;
+//!
+//! #[derive(garde::Validate)]
+//! struct Booking {
+//!     #[garde(range(min = date!(2024-01-01), max = date!(2024-12-31)))]
+//!     date: time::Date,
+//! }
+//! ```
+//!
+//! If `min` or `max` is omitted, that side of the range is not checked at all. For example, `range(max = 0.0)`
+//! accepts `f64::NEG_INFINITY`, even though it is lower than `f64::MIN`.
+//!
+//! The error carries the bounds and the value as params (see [`Error::param`](crate::Error::param)),
+//! so that a consumer can build its own message:
+//!
+//! - `min`: the lower bound, if one was given (for `equal`, the expected value)
+//! - `max`: the upper bound, if one was given (for `equal`, the expected value)
+//! - `value`: the value which was out of range, if the type reports it through [`Bounds::value_param`]
+//!
+//! All params are formatted with `Display`. Every implementation in this crate reports the value.
 
-use std::fmt::Display;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::rc::Rc;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use core::fmt::Display;
 
 use crate::error::Error;
 
@@ -21,21 +86,62 @@ pub fn apply<T: Bounds>(
     v: &T,
     (min, max): (Option<T::Size>, Option<T::Size>),
 ) -> Result<(), Error> {
-    let min = min.unwrap_or(T::MIN);
-    let max = max.unwrap_or(T::MAX);
-    if let Err(e) = v.validate_bounds(min, max) {
-        match e {
-            OutOfBounds::Lower => return Err(Error::new(format!("lower than {min}"))),
-            OutOfBounds::Upper => return Err(Error::new(format!("greater than {max}"))),
-        }
+    let error = match v.validate_bounds(min.unwrap_or(T::MIN), max.unwrap_or(T::MAX)) {
+        Ok(()) => return Ok(()),
+        Err(OutOfBounds::Lower) => match min {
+            Some(min) => Error::new(format!("lower than {min}")),
+            None => return Ok(()),
+        },
+        Err(OutOfBounds::Upper) => match max {
+            Some(max) => Error::new(format!("greater than {max}")),
+            None => return Ok(()),
+        },
+    };
+    Err(with_params(error, v, min, max))
+}
+
+/// Entrypoint for `range(equal = ...)`.
+#[inline]
+pub fn equal<T: Bounds>(v: &T, (equal,): (T::Size,)) -> Result<(), Error> {
+    match v.validate_bounds(equal, equal) {
+        Ok(()) => Ok(()),
+        Err(_) => Err(with_params(
+            Error::new(format!("must equal {equal}")),
+            v,
+            Some(equal),
+            Some(equal),
+        )),
+    }
+}
+
+fn with_params<T: Bounds>(
+    mut error: Error,
+    v: &T,
+    min: Option<T::Size>,
+    max: Option<T::Size>,
+) -> Error {
+    if let Some(min) = min {
+        error = error.with_param("min", min);
     }
-    Ok(())
+    if let Some(max) = max {
+        error = error.with_param("max", max);
+    }
+    if let Some(value) = v.value_param() {
+        error = error.with_param("value", value);
+    }
+    error
 }
 
 pub trait Bounds: PartialOrd {
     type Size: Copy + Sized + Display;
 
+    /// Passed to [`Bounds::validate_bounds`] if `min` is omitted.
+    ///
+    /// An omitted bound is never reported, so this doesn't have to be the smallest possible value.
     const MIN: Self::Size;
+    /// Passed to [`Bounds::validate_bounds`] if `max` is omitted.
+    ///
+    /// An omitted bound is never reported, so this doesn't have to be the largest possible value.
     const MAX: Self::Size;
 
     fn validate_bounds(
@@ -43,6 +149,13 @@ pub trait Bounds: PartialOrd {
         lower_bound: Self::Size,
         upper_bound: Self::Size,
     ) -> Result<(), OutOfBounds>;
+
+    /// The value as reported in the `value` param of an error, if any.
+    ///
+    /// The default implementation returns `None`, in which case the param is omitted.
+    fn value_param(&self) -> Option<String> {
+        None
+    }
 }
 
 pub enum OutOfBounds {
@@ -50,14 +163,14 @@ pub enum OutOfBounds {
     Upper,
 }
 
-macro_rules! impl_for_int {
-    ($($T:ident),*) => {
+macro_rules! impl_via_ord {
+    ($($T:ty => ($MIN:expr, $MAX:expr $(,)?)),* $(,)?) => {
         $(
             impl Bounds for $T {
                 type Size = $T;
 
-                const MIN: Self::Size = $T::MIN;
-                const MAX: Self::Size = $T::MAX;
+                const MIN: Self::Size = $MIN;
+                const MAX: Self::Size = $MAX;
 
                 fn validate_bounds(
                     &self,
@@ -72,13 +185,103 @@ macro_rules! impl_for_int {
                         Ok(())
                     }
                 }
+
+                fn value_param(&self) -> Option<String> {
+                    Some(self.to_string())
+                }
             }
         )*
     };
 }
 
+macro_rules! impl_for_int {
+    ($($T:ident),*) => {
+        impl_via_ord!($($T => ($T::MIN, $T::MAX)),*);
+    };
+}
+
 impl_for_int!(u8, u16, u32, u64, usize, u128, i8, i16, i32, i64, isize, i128, f32, f64);
 
+impl_via_ord!(char => ('\0', char::MAX));
+
+macro_rules! impl_for_nonzero {
+    ($($T:ident => $Size:ident),* $(,)?) => {
+        $(
+            impl Bounds for core::num::$T {
+                type Size = $Size;
+
+                const MIN: Self::Size = core::num::$T::MIN.get();
+                const MAX: Self::Size = core::num::$T::MAX.get();
+
+                fn validate_bounds(
+                    &self,
+                    lower_bound: Self::Size,
+                    upper_bound: Self::Size,
+                ) -> Result<(), OutOfBounds> {
+                    self.get().validate_bounds(lower_bound, upper_bound)
+                }
+
+                fn value_param(&self) -> Option<String> {
+                    Some(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_for_nonzero!(
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroUsize => usize,
+    NonZeroU128 => u128,
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroIsize => isize,
+    NonZeroI128 => i128,
+);
+
+#[cfg(feature = "time")]
+impl_via_ord!(
+    time::Date => (time::Date::MIN, time::Date::MAX),
+    time::Time => (time::Time::MIDNIGHT, time::Time::MAX),
+    time::PrimitiveDateTime => (time::PrimitiveDateTime::MIN, time::PrimitiveDateTime::MAX),
+    time::OffsetDateTime => (
+        time::PrimitiveDateTime::MIN.assume_utc(),
+        time::PrimitiveDateTime::MAX.assume_utc(),
+    ),
+);
+
+macro_rules! impl_via_deref {
+    ($($T:ident),*) => {
+        $(
+            impl<T: Bounds> Bounds for $T<T> {
+                type Size = T::Size;
+
+                const MIN: Self::Size = T::MIN;
+                const MAX: Self::Size = T::MAX;
+
+                fn validate_bounds(
+                    &self,
+                    lower_bound: Self::Size,
+                    upper_bound: Self::Size,
+                ) -> Result<(), OutOfBounds> {
+                    T::validate_bounds(self, lower_bound, upper_bound)
+                }
+
+                fn value_param(&self) -> Option<String> {
+                    T::value_param(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_via_deref!(Box, Rc, Arc);
+
 impl<T: Bounds> Bounds for Option<T> {
     type Size = T::Size;
 
@@ -95,4 +298,8 @@ impl<T: Bounds> Bounds for Option<T> {
             None => Ok(()),
         }
     }
+
+    fn value_param(&self) -> Option<String> {
+        self.as_ref().and_then(T::value_param)
+    }
 }
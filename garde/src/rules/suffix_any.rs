@@ -0,0 +1,28 @@
+//! Suffix set validation, requiring at least one of the suffixes.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(suffix_any(["+1", "+44"]))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The suffixes must be non-empty string literals, and there must be at least one of them.
+//!
+//! This rule uses the [`Suffix`] trait, so it may be used with any type which supports the `#[garde(suffix)]` rule.
+
+use alloc::format;
+
+use super::suffix::Suffix;
+use crate::error::Error;
+
+pub fn apply<T: Suffix>(v: &T, (pats,): (&[&str],)) -> Result<(), Error> {
+    if !pats.iter().any(|pat| v.validate_suffix(pat)) {
+        return Err(Error::new(format!(
+            "must end with one of: {}",
+            pats.join(", ")
+        )));
+    }
+    Ok(())
+}
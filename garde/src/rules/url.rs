@@ -14,6 +14,19 @@
 //!
 //! If you need to implement this for a string-like type where a contiguous slice of the entire contents cannot be obtained,
 //! then there is currently no way for you to implement this trait.
+//!
+//! The `require_host` flag also rejects URLs without a host, such as `mailto:` or `data:` URLs,
+//! and the `https` flag only accepts URLs whose scheme is exactly `https`. The flags may be combined:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(url(https, require_host))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! With any flag set, the rule uses the [`ParseUrl`] trait instead, which also has a blanket implementation for all `T: garde::rules::AsStr`.
 
 use std::fmt::Display;
 
@@ -27,6 +40,18 @@ pub fn apply<T: Url>(v: &T, _: ()) -> Result<(), Error> {
     Ok(())
 }
 
+/// Returns `true` if `v` is a valid URL.
+///
+/// This is the standalone equivalent of the `#[garde(url)]` rule.
+///
+/// ```rust
+/// assert!(garde::rules::url::is_valid("https://example.com"));
+/// assert!(!garde::rules::url::is_valid("example"));
+/// ```
+pub fn is_valid(v: &str) -> bool {
+    apply(&v, ()).is_ok()
+}
+
 pub trait Url {
     type Error: Display;
 
@@ -52,3 +77,61 @@ impl<T: Url> Url for Option<T> {
         }
     }
 }
+
+/// Entrypoint for `url` with the `require_host` or `https` flags set.
+///
+/// The URL is parsed once, and only the first failing check is reported.
+pub fn apply_with<T: ParseUrl>(v: &T, (require_host, https): (bool, bool)) -> Result<(), Error> {
+    let url = match v.parse_url() {
+        Ok(Some(url)) => url,
+        Ok(None) => return Ok(()),
+        Err(e) => return Err(Error::new(format!("not a valid url: {e}"))),
+    };
+    if require_host && url.host().is_none() {
+        return Err(Error::new("URL must have a host"));
+    }
+    if https && url.scheme() != "https" {
+        return Err(Error::new("URL must use HTTPS"));
+    }
+    Ok(())
+}
+
+/// Returns `true` if `v` is a valid URL which passes the given flags.
+///
+/// This is the standalone equivalent of the `#[garde(url(require_host, https))]` rule,
+/// with each flag only checked if it is `true`.
+///
+/// ```rust
+/// assert!(garde::rules::url::is_valid_with("https://example.com", true, true));
+/// assert!(!garde::rules::url::is_valid_with("mailto:user@example.com", true, false));
+/// assert!(!garde::rules::url::is_valid_with("http://example.com", false, true));
+/// ```
+pub fn is_valid_with(v: &str, require_host: bool, https: bool) -> bool {
+    apply_with(&v, (require_host, https)).is_ok()
+}
+
+pub trait ParseUrl {
+    type Error: Display;
+
+    /// Returns `Ok(None)` if there is no value to validate.
+    fn parse_url(&self) -> Result<Option<url::Url>, Self::Error>;
+}
+
+impl<T: AsStr> ParseUrl for T {
+    type Error = url::ParseError;
+
+    fn parse_url(&self) -> Result<Option<url::Url>, Self::Error> {
+        url::Url::parse(self.as_str()).map(Some)
+    }
+}
+
+impl<T: ParseUrl> ParseUrl for Option<T> {
+    type Error = T::Error;
+
+    fn parse_url(&self) -> Result<Option<url::Url>, Self::Error> {
+        match self {
+            Some(value) => value.parse_url(),
+            None => Ok(None),
+        }
+    }
+}
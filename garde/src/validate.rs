@@ -1,14 +1,64 @@
 //! ## Core validation traits and types
+//!
+//! This module also contains a function for each built-in rule, which validates a single value
+//! without deriving [`Validate`], e.g. [`length`].
 
-use std::fmt::Debug;
+mod standalone;
 
+use alloc::borrow::ToOwned;
+use core::fmt::Debug;
+
+pub use self::standalone::*;
 use crate::error::{Path, PathComponentKind};
-use crate::Report;
+use crate::{Error, Report};
 
 /// The core trait of this crate.
 ///
 /// Validation runs the fields through every validation rules,
 /// and aggregates any errors into a [`Report`].
+///
+/// This trait is implemented for references, smart pointers (`Box`, `Rc`, `Arc`, `Cow`), `Option`, `Result`,
+/// tuples, arrays, slices, and the standard collections, including `HashMap` and `BTreeMap`,
+/// whenever the type they contain implements it. These implementations validate every item
+/// using the same context, so they all have the `Context` of the item type.
+/// This is what allows `#[garde(dive)]` to be used with them directly.
+///
+/// The trait is object-safe once its `Context` is fixed, so values of different types which share a context
+/// may be stored and validated as trait objects. Because the context is part of the trait object's type,
+/// there is no way to hold items with different contexts behind the same `dyn Validate`.
+///
+/// ```rust
+/// use garde::Validate;
+///
+/// #[derive(garde::Validate)]
+/// struct Name {
+///     #[garde(length(min = 1))]
+///     value: String,
+/// }
+///
+/// #[derive(garde::Validate)]
+/// struct Age {
+///     #[garde(range(max = 150))]
+///     value: u8,
+/// }
+///
+/// #[derive(garde::Validate)]
+/// struct Items {
+///     #[garde(dive)]
+///     items: Vec<Box<dyn Validate<Context = ()>>>,
+/// }
+///
+/// let items = Items {
+///     items: vec![
+///         Box::new(Name { value: String::new() }),
+///         Box::new(Age { value: 30 }),
+///     ],
+/// };
+/// assert_eq!(
+///     items.validate().unwrap_err().to_string(),
+///     "items[0].value: length is lower than 1\n",
+/// );
+/// ```
 pub trait Validate {
     /// A user-provided context.
     ///
@@ -36,9 +86,23 @@ pub trait Validate {
     fn validate_with(&self, ctx: &Self::Context) -> Result<(), Report> {
         let mut report = Report::new();
         self.validate_into(ctx, &mut Path::empty, &mut report);
-        match report.is_empty() {
-            true => Ok(()),
-            false => Err(report),
+        report.into_result()
+    }
+
+    /// Validates `Self`, returning an `Err` with the first error encountered
+    /// along with its path if the validation failed.
+    ///
+    /// Unlike [`Validate::validate_with`], this stops validating as soon as a single
+    /// error is found, which is cheaper if you only care whether the value is valid.
+    ///
+    /// This method should not be implemented manually. Implement [`Validate::validate_into`] instead,
+    /// because [`Validate::validate_first_error`] has a default implementation that calls [`Validate::validate_into`].
+    fn validate_first_error(&self, ctx: &Self::Context) -> Result<(), (Path, Error)> {
+        let mut report = Report::fail_fast();
+        self.validate_into(ctx, &mut Path::empty, &mut report);
+        match report.into_inner().into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(()),
         }
     }
 
@@ -67,7 +131,7 @@ impl<T: Validate> Valid<T> {
     }
 }
 
-impl<T> std::ops::Deref for Valid<T> {
+impl<T> core::ops::Deref for Valid<T> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
@@ -115,7 +179,7 @@ impl<T: Validate> From<T> for Unvalidated<T> {
 }
 
 impl<T: Debug> Debug for Unvalidated<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         Debug::fmt(&self.0, f)
     }
 }
@@ -146,7 +210,7 @@ impl<'a, T: ?Sized + Validate> Validate for &'a mut T {
     }
 }
 
-impl<T: Validate> Validate for std::boxed::Box<T> {
+impl<T: ?Sized + Validate> Validate for alloc::boxed::Box<T> {
     type Context = T::Context;
 
     fn validate_into(
@@ -159,7 +223,7 @@ impl<T: Validate> Validate for std::boxed::Box<T> {
     }
 }
 
-impl<T: Validate> Validate for std::rc::Rc<T> {
+impl<T: ?Sized + Validate> Validate for alloc::rc::Rc<T> {
     type Context = T::Context;
 
     fn validate_into(
@@ -172,7 +236,7 @@ impl<T: Validate> Validate for std::rc::Rc<T> {
     }
 }
 
-impl<T: Validate> Validate for std::sync::Arc<T> {
+impl<T: ?Sized + Validate> Validate for alloc::sync::Arc<T> {
     type Context = T::Context;
 
     fn validate_into(
@@ -185,6 +249,74 @@ impl<T: Validate> Validate for std::sync::Arc<T> {
     }
 }
 
+/// The value is borrowed with [`RefCell::try_borrow`][`core::cell::RefCell::try_borrow`].
+/// If it is currently mutably borrowed, an error is reported instead of validating it.
+impl<T: ?Sized + Validate> Validate for core::cell::RefCell<T> {
+    type Context = T::Context;
+
+    fn validate_into(
+        &self,
+        ctx: &Self::Context,
+        parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        match self.try_borrow() {
+            Ok(value) => <T as Validate>::validate_into(&value, ctx, parent, report),
+            Err(_) => report.append(parent(), Error::new("value is already mutably borrowed")),
+        }
+    }
+}
+
+/// The value is locked with [`Mutex::try_lock`][`std::sync::Mutex::try_lock`], so validation never blocks.
+/// If the mutex is currently locked, including by the thread doing the validation, an error is reported
+/// instead of validating the value. A poisoned mutex is still validated.
+#[cfg(feature = "std")]
+impl<T: ?Sized + Validate> Validate for std::sync::Mutex<T> {
+    type Context = T::Context;
+
+    fn validate_into(
+        &self,
+        ctx: &Self::Context,
+        parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        match self.try_lock() {
+            Ok(value) => <T as Validate>::validate_into(&value, ctx, parent, report),
+            Err(std::sync::TryLockError::Poisoned(e)) => {
+                <T as Validate>::validate_into(&e.into_inner(), ctx, parent, report)
+            }
+            Err(std::sync::TryLockError::WouldBlock) => {
+                report.append(parent(), Error::new("value is locked"))
+            }
+        }
+    }
+}
+
+/// The value is locked with [`RwLock::try_read`][`std::sync::RwLock::try_read`], so validation never blocks.
+/// If the lock is currently held by a writer, an error is reported instead of validating the value.
+/// A poisoned lock is still validated.
+#[cfg(feature = "std")]
+impl<T: ?Sized + Validate> Validate for std::sync::RwLock<T> {
+    type Context = T::Context;
+
+    fn validate_into(
+        &self,
+        ctx: &Self::Context,
+        parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        match self.try_read() {
+            Ok(value) => <T as Validate>::validate_into(&value, ctx, parent, report),
+            Err(std::sync::TryLockError::Poisoned(e)) => {
+                <T as Validate>::validate_into(&e.into_inner(), ctx, parent, report)
+            }
+            Err(std::sync::TryLockError::WouldBlock) => {
+                report.append(parent(), Error::new("value is locked"))
+            }
+        }
+    }
+}
+
 macro_rules! impl_validate_list {
     (<$T:ident $(, $Other:ident)*> $Container:ty) => {
         impl<$T, $($Other),*> Validate for $Container
@@ -197,20 +329,58 @@ macro_rules! impl_validate_list {
                 for (index, item) in self.iter().enumerate() {
                     let mut path = $crate::util::nested_path!(parent, index);
                     <T as Validate>::validate_into(item, ctx, &mut path, report);
+                    if report.should_stop() {
+                        return;
+                    }
                 }
             }
         }
     };
 }
 
+#[cfg(feature = "std")]
 impl_validate_list!(<T, S> std::collections::HashSet<T, S>);
-impl_validate_list!(<T> std::collections::BTreeSet<T>);
-impl_validate_list!(<T> std::collections::BinaryHeap<T>);
-impl_validate_list!(<T> std::collections::LinkedList<T>);
-impl_validate_list!(<T> std::collections::VecDeque<T>);
-impl_validate_list!(<T> std::vec::Vec<T>);
+#[cfg(feature = "indexmap")]
+impl_validate_list!(<T, S> indexmap::IndexSet<T, S>);
+impl_validate_list!(<T> alloc::collections::BTreeSet<T>);
+impl_validate_list!(<T> alloc::collections::BinaryHeap<T>);
+impl_validate_list!(<T> alloc::collections::LinkedList<T>);
+impl_validate_list!(<T> alloc::collections::VecDeque<T>);
+impl_validate_list!(<T> alloc::vec::Vec<T>);
 impl_validate_list!(<T> [T]);
 
+#[cfg(feature = "smallvec")]
+impl<A> Validate for smallvec::SmallVec<A>
+where
+    A: smallvec::Array,
+    A::Item: Validate,
+{
+    type Context = <A::Item as Validate>::Context;
+
+    fn validate_into(
+        &self,
+        ctx: &Self::Context,
+        parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        self.as_slice().validate_into(ctx, parent, report)
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T: Validate, const CAP: usize> Validate for arrayvec::ArrayVec<T, CAP> {
+    type Context = T::Context;
+
+    fn validate_into(
+        &self,
+        ctx: &Self::Context,
+        parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        self.as_slice().validate_into(ctx, parent, report)
+    }
+}
+
 impl<T: Validate, const N: usize> Validate for [T; N] {
     type Context = T::Context;
 
@@ -223,6 +393,9 @@ impl<T: Validate, const N: usize> Validate for [T; N] {
         for (index, item) in self.iter().enumerate() {
             let mut path = crate::util::nested_path!(parent, index);
             <T as Validate>::validate_into(item, ctx, &mut path, report);
+            if report.should_stop() {
+                return;
+            }
         }
     }
 }
@@ -246,6 +419,9 @@ macro_rules! impl_validate_tuple {
                 drop(path);
                 index += 1;
                 $({
+                    if report.should_stop() {
+                        return;
+                    }
                     let _index = index;
                     let mut path = $crate::util::nested_path!(parent, _index);
                     <$T as Validate>::validate_into($T, ctx, &mut path, report);
@@ -277,6 +453,7 @@ impl Validate for () {
     fn validate_into(&self, _: &Self::Context, _: &mut dyn FnMut() -> Path, _: &mut Report) {}
 }
 
+#[cfg(feature = "std")]
 impl<K, V, S> Validate for std::collections::HashMap<K, V, S>
 where
     K: Clone + PathComponentKind,
@@ -293,11 +470,39 @@ where
         for (key, value) in self.iter() {
             let mut path = crate::util::nested_path!(parent, key);
             <V as Validate>::validate_into(value, ctx, &mut path, report);
+            if report.should_stop() {
+                return;
+            }
+        }
+    }
+}
+
+/// Entries are validated in insertion order, so errors are reported in the same order as the map.
+#[cfg(feature = "indexmap")]
+impl<K, V, S> Validate for indexmap::IndexMap<K, V, S>
+where
+    K: Clone + PathComponentKind,
+    V: Validate,
+{
+    type Context = V::Context;
+
+    fn validate_into(
+        &self,
+        ctx: &Self::Context,
+        mut parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        for (key, value) in self.iter() {
+            let mut path = crate::util::nested_path!(parent, key);
+            <V as Validate>::validate_into(value, ctx, &mut path, report);
+            if report.should_stop() {
+                return;
+            }
         }
     }
 }
 
-impl<K, V> Validate for std::collections::BTreeMap<K, V>
+impl<K, V> Validate for alloc::collections::BTreeMap<K, V>
 where
     K: Clone + PathComponentKind,
     V: Validate,
@@ -313,6 +518,9 @@ where
         for (key, value) in self.iter() {
             let mut path = crate::util::nested_path!(parent, key);
             <V as Validate>::validate_into(value, ctx, &mut path, report);
+            if report.should_stop() {
+                return;
+            }
         }
     }
 }
@@ -332,7 +540,36 @@ impl<T: Validate> Validate for Option<T> {
     }
 }
 
-impl<'a, B: Validate> Validate for std::borrow::Cow<'a, B>
+/// Validates whichever of the two variants is present, at the key `ok` or `err`.
+///
+/// To only validate the `Ok` variant, use `#[garde(inner(dive))]` instead of `#[garde(dive)]`.
+impl<T, E> Validate for Result<T, E>
+where
+    T: Validate,
+    E: Validate<Context = T::Context>,
+{
+    type Context = T::Context;
+
+    fn validate_into(
+        &self,
+        ctx: &Self::Context,
+        mut parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        match self {
+            Ok(value) => {
+                let mut path = crate::util::nested_path!(parent, "ok");
+                value.validate_into(ctx, &mut path, report)
+            }
+            Err(error) => {
+                let mut path = crate::util::nested_path!(parent, "err");
+                error.validate_into(ctx, &mut path, report)
+            }
+        }
+    }
+}
+
+impl<'a, B: Validate> Validate for alloc::borrow::Cow<'a, B>
 where
     B: ToOwned,
 {
@@ -0,0 +1,255 @@
+//! Functions which validate a single value against one rule.
+//!
+//! Each function is the standalone equivalent of a `#[garde(...)]` rule with the same name,
+//! and returns the same error as the rule would. Length bounds are inclusive.
+//!
+//! ```rust
+//! assert!(garde::validate::length(&"garde", 3, 20).is_ok());
+//! assert_eq!(
+//!     garde::validate::length(&"ga", 3, 20).unwrap_err().message(),
+//!     "length is lower than 3",
+//! );
+//! assert!(garde::validate::range(&5u8, Some(1), None).is_ok());
+//! ```
+
+use core::fmt::Debug;
+
+use crate::error::Error;
+use crate::rules;
+
+/// Checks that `v` only contains ASCII characters, like `#[garde(ascii)]`.
+pub fn ascii<T: rules::ascii::Ascii>(v: &T) -> Result<(), Error> {
+    rules::ascii::apply(v, ())
+}
+
+/// Checks that `v` only contains printable ASCII characters, like `#[garde(ascii(printable))]`.
+pub fn ascii_printable<T: rules::ascii::printable::PrintableAscii>(v: &T) -> Result<(), Error> {
+    rules::ascii::printable::apply(v, ())
+}
+
+/// Checks that `v` only contains letters and digits, like `#[garde(alphanumeric)]`.
+pub fn alphanumeric<T: rules::alphanumeric::Alphanumeric>(v: &T) -> Result<(), Error> {
+    rules::alphanumeric::apply(v, ())
+}
+
+/// Checks that no character of `v` is outside of `charset`, like `#[garde(charset(...))]`.
+pub fn charset<T: rules::charset::MaxCodepoint>(
+    v: &T,
+    charset: rules::charset::Charset,
+) -> Result<(), Error> {
+    rules::charset::apply(v, (charset,))
+}
+
+/// Checks that every character of `v` passes `predicate`, like `#[garde(each_char(predicate))]`.
+pub fn each_char<T: rules::each_char::EachChar, F: Fn(char) -> bool>(
+    v: &T,
+    predicate: F,
+) -> Result<(), Error> {
+    rules::each_char::apply(v, (&predicate,))
+}
+
+/// Checks that `v` is an email address, like `#[garde(email)]`.
+#[cfg(feature = "email")]
+pub fn email<T: rules::email::Email>(v: &T) -> Result<(), Error> {
+    rules::email::apply(v, ())
+}
+
+/// Checks that `v` is a URL, like `#[garde(url)]`.
+#[cfg(feature = "url")]
+pub fn url<T: rules::url::Url>(v: &T) -> Result<(), Error> {
+    rules::url::apply(v, ())
+}
+
+/// Checks that `v` is a URL which passes the given flags, like `#[garde(url(require_host, https))]`.
+#[cfg(feature = "url")]
+pub fn url_with<T: rules::url::ParseUrl>(
+    v: &T,
+    require_host: bool,
+    https: bool,
+) -> Result<(), Error> {
+    rules::url::apply_with(v, (require_host, https))
+}
+
+/// Checks that `v` is an IP address of the given kind, like `#[garde(ip)]`, `#[garde(ipv4)]` and `#[garde(ipv6)]`.
+#[cfg(feature = "std")]
+pub fn ip<T: rules::ip::Ip>(v: &T, kind: rules::ip::IpKind) -> Result<(), Error> {
+    rules::ip::apply(v, (kind,))
+}
+
+/// Checks that `v` is a CIDR block of the given kind, like `#[garde(cidr)]`, `#[garde(cidrv4)]` and `#[garde(cidrv6)]`.
+#[cfg(feature = "std")]
+pub fn cidr<T: rules::cidr::Cidr>(v: &T, kind: rules::ip::IpKind) -> Result<(), Error> {
+    rules::cidr::apply(v, (kind,))
+}
+
+/// Checks that `v` is a credit card number, like `#[garde(credit_card)]`.
+#[cfg(feature = "credit-card")]
+pub fn credit_card<T: rules::credit_card::CreditCard>(v: &T) -> Result<(), Error> {
+    rules::credit_card::apply(v, ())
+}
+
+/// Checks that `v` is a phone number, like `#[garde(phone_number)]`.
+#[cfg(feature = "phone-number")]
+pub fn phone_number<T: rules::phone_number::PhoneNumber>(v: &T) -> Result<(), Error> {
+    rules::phone_number::apply(v, ())
+}
+
+/// Checks that `v` is a single safe path component, like `#[garde(path_component)]`.
+pub fn path_component<T: rules::path::SafePathComponent>(v: &T) -> Result<(), Error> {
+    rules::path::apply(v, ())
+}
+
+/// Checks that the simple length of `v` is within `min..=max`, like `#[garde(length(min, max))]`.
+pub fn length<T: rules::length::simple::Simple>(
+    v: &T,
+    min: usize,
+    max: usize,
+) -> Result<(), Error> {
+    rules::length::simple::apply(v, (min, max))
+}
+
+/// Checks that the length of `v` in bytes is within `min..=max`, like `#[garde(length(bytes, min, max))]`.
+pub fn length_bytes<T: rules::length::bytes::Bytes>(
+    v: &T,
+    min: usize,
+    max: usize,
+) -> Result<(), Error> {
+    rules::length::bytes::apply(v, (min, max))
+}
+
+/// Checks that the number of characters in `v` is within `min..=max`, like `#[garde(length(chars, min, max))]`.
+pub fn length_chars<T: rules::length::chars::Chars>(
+    v: &T,
+    min: usize,
+    max: usize,
+) -> Result<(), Error> {
+    rules::length::chars::apply(v, (min, max))
+}
+
+/// Checks that the number of graphemes in `v` is within `min..=max`, like `#[garde(length(graphemes, min, max))]`.
+#[cfg(feature = "unicode")]
+pub fn length_graphemes<T: rules::length::graphemes::Graphemes>(
+    v: &T,
+    min: usize,
+    max: usize,
+) -> Result<(), Error> {
+    rules::length::graphemes::apply(v, (min, max))
+}
+
+/// Checks that the number of UTF-16 code units in `v` is within `min..=max`, like `#[garde(length(utf16, min, max))]`.
+pub fn length_utf16<T: rules::length::utf16::Utf16CodeUnits>(
+    v: &T,
+    min: usize,
+    max: usize,
+) -> Result<(), Error> {
+    rules::length::utf16::apply(v, (min, max))
+}
+
+/// Checks that `v` is within `min..=max`, like `#[garde(range(min, max))]`.
+///
+/// A bound of `None` is not checked.
+pub fn range<T: rules::range::Bounds>(
+    v: &T,
+    min: Option<T::Size>,
+    max: Option<T::Size>,
+) -> Result<(), Error> {
+    rules::range::apply(v, (min, max))
+}
+
+/// Checks that `v` is a byte size within `min..=max`, like `#[garde(byte_size(min, max))]`.
+///
+/// A bound of `None` is not checked.
+#[cfg(feature = "byte-size")]
+pub fn byte_size<T: rules::byte_size::ByteSize>(
+    v: &T,
+    min: Option<rules::byte_size::Size>,
+    max: Option<rules::byte_size::Size>,
+) -> Result<(), Error> {
+    rules::byte_size::apply(v, (min, max))
+}
+
+/// Checks that `v` contains `pat`, like `#[garde(contains(pat))]`.
+pub fn contains<T: rules::contains::Contains>(v: &T, pat: &str) -> Result<(), Error> {
+    rules::contains::apply(v, (pat,))
+}
+
+/// Checks that `v` contains the character `pat`, like `#[garde(contains('x'))]`.
+pub fn contains_char<T: rules::contains::Contains>(v: &T, pat: char) -> Result<(), Error> {
+    rules::contains::single_char::apply(v, (pat,))
+}
+
+/// Checks that `v` contains at least one of `pats`, like `#[garde(contains_any(pats))]`.
+pub fn contains_any<T: rules::contains::Contains>(v: &T, pats: &[&str]) -> Result<(), Error> {
+    rules::contains_any::apply(v, (pats,))
+}
+
+/// Checks that `v` contains all of `pats`, like `#[garde(contains_all(pats))]`.
+pub fn contains_all<T: rules::contains::Contains>(v: &T, pats: &[&str]) -> Result<(), Error> {
+    rules::contains_all::apply(v, (pats,))
+}
+
+/// Checks that the collection `v` contains `item`, like `#[garde(contains_item(item))]`.
+pub fn contains_item<T: rules::contains_item::ContainsItem<U>, U: Debug + ?Sized>(
+    v: &T,
+    item: &U,
+) -> Result<(), Error> {
+    rules::contains_item::apply(v, (item,))
+}
+
+/// Checks that `v` starts with `pat`, like `#[garde(prefix(pat))]`.
+pub fn prefix<T: rules::prefix::Prefix>(v: &T, pat: &str) -> Result<(), Error> {
+    rules::prefix::apply(v, (pat,))
+}
+
+/// Checks that `v` starts with one of `pats`, like `#[garde(prefix_any(pats))]`.
+pub fn prefix_any<T: rules::prefix::Prefix>(v: &T, pats: &[&str]) -> Result<(), Error> {
+    rules::prefix_any::apply(v, (pats,))
+}
+
+/// Checks that `v` ends with `pat`, like `#[garde(suffix(pat))]`.
+pub fn suffix<T: rules::suffix::Suffix>(v: &T, pat: &str) -> Result<(), Error> {
+    rules::suffix::apply(v, (pat,))
+}
+
+/// Checks that `v` ends with one of `pats`, like `#[garde(suffix_any(pats))]`.
+pub fn suffix_any<T: rules::suffix::Suffix>(v: &T, pats: &[&str]) -> Result<(), Error> {
+    rules::suffix_any::apply(v, (pats,))
+}
+
+/// Checks that `v` matches the glob `pattern`, like `#[garde(glob(pattern))]`.
+pub fn glob<T: rules::glob::Glob>(v: &T, pattern: &str) -> Result<(), Error> {
+    rules::glob::apply(v, (pattern,))
+}
+
+/// Checks that `v` is matched by `matcher`, like `#[garde(pattern(matcher))]`.
+pub fn pattern<T: rules::pattern::Pattern, M: rules::pattern::Matcher>(
+    v: &T,
+    matcher: &M,
+) -> Result<(), Error> {
+    rules::pattern::apply(v, (matcher, None))
+}
+
+/// Checks that `v` is not matched by `matcher`, like `#[garde(not_pattern(matcher))]`.
+pub fn not_pattern<T: rules::not_pattern::NotPattern, M: rules::pattern::Matcher>(
+    v: &T,
+    matcher: &M,
+) -> Result<(), Error> {
+    rules::not_pattern::apply(v, (matcher,))
+}
+
+/// Checks that `v` is matched by `matcher` and passes `check` on its captures, like `#[garde(pattern_captures(matcher, check))]`.
+#[cfg(feature = "regex")]
+pub fn pattern_captures<T, M, F, C>(v: &T, matcher: &M, check: F, ctx: &C) -> Result<(), Error>
+where
+    T: rules::pattern_captures::PatternCaptures,
+    M: rules::pattern_captures::CaptureMatcher,
+    F: Fn(&rules::pattern_captures::Captures<'_>, &C) -> Result<(), Error>,
+    C: ?Sized,
+{
+    rules::pattern_captures::apply(v, (matcher, &check, ctx))
+}
+
+/// Checks that `v` is present, like `#[garde(required)]`.
+pub fn required<T: rules::required::Required>(v: &T) -> Result<(), Error> {
+    rules::required::apply(v, ())
+}
@@ -0,0 +1,80 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Inner<'a> {
+    #[garde(length(min = 1))]
+    value: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(rename("full_name"), length(min = 1))]
+    name: &'a str,
+    #[garde(rename("emailAddress"), alias("email"), alias("mail"), email)]
+    email_address: &'a str,
+    #[garde(alias("old_items"), dive)]
+    items: Vec<Inner<'a>>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Tuple<'a>(#[garde(alias("first"), length(min = 1))] &'a str);
+
+#[test]
+fn alias_valid() {
+    util::check_ok(
+        &[Test {
+            name: "Jane",
+            email_address: "jane@example.com",
+            items: vec![Inner { value: "a" }],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn alias_invalid() {
+    util::check_fail!(
+        &[Test {
+            name: "",
+            email_address: "jane",
+            items: vec![Inner { value: "a" }, Inner { value: "" }],
+        }],
+        &()
+    )
+}
+
+#[test]
+fn alias_tuple_invalid() {
+    util::check_fail!(&[Tuple("")], &())
+}
+
+#[test]
+fn alias_fail_fast() {
+    let value = Test {
+        name: "Jane",
+        email_address: "jane",
+        items: vec![],
+    };
+    let (path, error) = garde::Validate::validate_first_error(&value, &()).unwrap_err();
+    assert_eq!(path.to_string(), "emailAddress");
+    assert!(error.message().starts_with("not a valid email"));
+}
+
+#[derive(Debug, garde::Validate)]
+struct NonIdent<'a> {
+    #[garde(rename("user-name"), alias("data.field"), length(min = 1))]
+    name: &'a str,
+    #[garde(rename("1st item"), length(min = 1))]
+    first: &'a str,
+}
+
+#[test]
+fn rename_non_ident_invalid() {
+    util::check_fail!(
+        &[NonIdent {
+            name: "",
+            first: ""
+        }],
+        &()
+    )
+}
@@ -31,3 +31,33 @@ fn ascii_invalid() {
         &()
     )
 }
+
+#[allow(dead_code)]
+#[derive(Debug, garde::Validate)]
+#[garde(allow_unvalidated)]
+struct Empty<'a> {
+    a: &'a str,
+    b: Vec<u8>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, garde::Validate)]
+#[garde(allow_unvalidated)]
+enum EmptyEnum<'a> {
+    Struct { a: &'a str },
+    Tuple(&'a str),
+}
+
+#[test]
+fn no_validated_fields_valid() {
+    util::check_ok(
+        &[Empty {
+            a: "😂", b: vec![]
+        }],
+        &(),
+    );
+    util::check_ok(
+        &[EmptyEnum::Struct { a: "😂" }, EmptyEnum::Tuple("😂")],
+        &(),
+    );
+}
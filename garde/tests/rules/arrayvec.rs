@@ -0,0 +1,49 @@
+#![cfg(feature = "arrayvec")]
+
+use arrayvec::ArrayVec;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Item<'a> {
+    #[garde(length(min = 1))]
+    name: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 1, max = 2))]
+    length: ArrayVec<u32, 4>,
+    #[garde(dive)]
+    dive: ArrayVec<Item<'a>, 2>,
+    #[garde(inner(length(min = 1)))]
+    inner: ArrayVec<&'a str, 4>,
+    #[garde(contains_item(1))]
+    contains_item: ArrayVec<u32, 4>,
+}
+
+#[test]
+fn arrayvec_valid() {
+    util::check_ok(
+        &[Test {
+            length: ArrayVec::from_iter([1, 2]),
+            dive: ArrayVec::from_iter([Item { name: "a" }]),
+            inner: ArrayVec::from_iter(["a", "b", "c"]),
+            contains_item: ArrayVec::from_iter([0, 1]),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn arrayvec_invalid() {
+    util::check_fail!(
+        &[Test {
+            length: ArrayVec::from_iter([1, 2, 3]),
+            dive: ArrayVec::from_iter([Item { name: "a" }, Item { name: "" }]),
+            inner: ArrayVec::from_iter(["a", ""]),
+            contains_item: ArrayVec::new(),
+        }],
+        &()
+    )
+}
@@ -37,3 +37,46 @@ fn ascii_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Printable<'a> {
+    #[garde(ascii(printable))]
+    field: &'a str,
+
+    #[garde(inner(ascii(printable)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn ascii_printable_valid() {
+    util::check_ok(
+        &[
+            Printable {
+                field: "a!0_~ ",
+                inner: &["a!0_~ "],
+            },
+            Printable {
+                field: "",
+                inner: &[""],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn ascii_printable_invalid() {
+    util::check_fail!(
+        &[
+            Printable {
+                field: "tab\there",
+                inner: &["bell\x07"],
+            },
+            Printable {
+                field: "😂",
+                inner: &["del\x7f"],
+            },
+        ],
+        &()
+    )
+}
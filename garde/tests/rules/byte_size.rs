@@ -0,0 +1,53 @@
+#![cfg(feature = "byte-size")]
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(byte_size(min = "1KB", max = "10MB"))]
+    field: &'a str,
+    #[garde(inner(byte_size(max = "1KiB")))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn byte_size_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "1KB",
+                inner: &["1KiB"],
+            },
+            Test {
+                field: "10 MB",
+                inner: &["1024"],
+            },
+            Test {
+                field: "1.5mb",
+                inner: &["0"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn byte_size_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "999B",
+                inner: &["1025"]
+            },
+            Test {
+                field: "10.1MB",
+                inner: &["1.1KiB"]
+            },
+            Test {
+                field: "lots",
+                inner: &["10 XB"]
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "bytes")]
+
+use bytes::{Bytes, BytesMut};
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test {
+    #[garde(length(max = 4))]
+    bytes: Bytes,
+    #[garde(length(bytes, min = 1, max = 4))]
+    bytes_mut: BytesMut,
+    #[garde(inner(length(min = 1)))]
+    inner: Vec<Bytes>,
+}
+
+#[test]
+fn bytes_valid() {
+    util::check_ok(
+        &[Test {
+            bytes: Bytes::from_static(b"abcd"),
+            bytes_mut: BytesMut::from(&b"a"[..]),
+            inner: vec![Bytes::from_static(b"a")],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn bytes_invalid() {
+    util::check_fail!(
+        &[Test {
+            bytes: Bytes::from_static(b"abcde"),
+            bytes_mut: BytesMut::new(),
+            inner: vec![Bytes::new()],
+        }],
+        &()
+    )
+}
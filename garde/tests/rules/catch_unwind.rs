@@ -0,0 +1,63 @@
+use garde::error::{Error, Report};
+
+use super::util;
+
+fn parse_code(value: &str, _: &()) -> garde::Result {
+    let code: u32 = value.parse().unwrap();
+    if code > 999 {
+        return Err(Error::new("code is too long"));
+    }
+    Ok(())
+}
+
+fn check_tags(value: &[&str], _: &()) -> Result<(), Report> {
+    assert!(!value.is_empty(), "no tags");
+    Ok(())
+}
+
+fn check_all(value: &Test, _: &()) -> Result<(), Report> {
+    assert!(value.code != "0", "code is zero");
+    Ok(())
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(catch_unwind)]
+#[garde(custom_fields(check_all))]
+struct Test<'a> {
+    #[garde(custom(parse_code), length(max = 3))]
+    code: &'a str,
+    #[garde(custom_report(check_tags))]
+    tags: &'a [&'a str],
+}
+
+#[test]
+fn catch_unwind_valid() {
+    util::check_ok(
+        &[Test {
+            code: "123",
+            tags: &["a"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn catch_unwind_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                code: "1234",
+                tags: &["a"],
+            },
+            Test {
+                code: "abcd",
+                tags: &[],
+            },
+            Test {
+                code: "0",
+                tags: &["a"],
+            },
+        ],
+        &()
+    )
+}
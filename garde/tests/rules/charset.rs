@@ -0,0 +1,49 @@
+use super::util;
+
+const BMP_MAX: u32 = 0xFFFF;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(charset(latin1))]
+    latin1: &'a str,
+    #[garde(charset(ascii))]
+    ascii: &'a str,
+    #[garde(charset(max = BMP_MAX))]
+    bmp: &'a str,
+    #[garde(inner(charset(latin1)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn charset_valid() {
+    util::check_ok(
+        &[
+            Test {
+                latin1: "Größe ÿ",
+                ascii: "plain~",
+                bmp: "日本語",
+                inner: &["café", ""],
+            },
+            Test {
+                latin1: "\u{FF}",
+                ascii: "\u{7F}",
+                bmp: "\u{FFFF}",
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn charset_invalid() {
+    util::check_fail!(
+        &[Test {
+            latin1: "Œuvre",
+            ascii: "é",
+            bmp: "😂",
+            inner: &["ok", "€"],
+        }],
+        &()
+    )
+}
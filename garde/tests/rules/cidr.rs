@@ -0,0 +1,97 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct TestCidrAny<'a> {
+    #[garde(cidr)]
+    field: &'a str,
+    #[garde(inner(cidr))]
+    inner: &'a [&'a str],
+}
+
+#[derive(Debug, garde::Validate)]
+struct TestCidrV4<'a> {
+    #[garde(cidrv4)]
+    field: &'a str,
+    #[garde(inner(cidrv4))]
+    inner: &'a [&'a str],
+}
+
+#[derive(Debug, garde::Validate)]
+struct TestCidrV6<'a> {
+    #[garde(cidrv6)]
+    field: &'a str,
+    #[garde(inner(cidrv6))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn cidr_any_valid() {
+    util::check_ok(
+        &[
+            TestCidrAny {
+                field: "10.0.0.0/24",
+                inner: &["0.0.0.0/0", "192.168.1.1/32"],
+            },
+            TestCidrAny {
+                field: "2001:db8::/32",
+                inner: &["::/0", "fe80::1/128"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn cidr_any_invalid() {
+    util::check_fail!(
+        &[TestCidrAny {
+            field: "10.0.0.0",
+            inner: &["10.0.0.0/33", "10.0.0.0/", "2001:db8::/129", "10.0.0.0/+8"],
+        }],
+        &()
+    )
+}
+
+#[test]
+fn cidr_v4_valid() {
+    util::check_ok(
+        &[TestCidrV4 {
+            field: "172.16.0.0/12",
+            inner: &["10.0.0.1/8"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn cidr_v4_invalid() {
+    util::check_fail!(
+        &[TestCidrV4 {
+            field: "2001:db8::/32",
+            inner: &["256.0.0.0/8", "10.0.0.0/24/8"],
+        }],
+        &()
+    )
+}
+
+#[test]
+fn cidr_v6_valid() {
+    util::check_ok(
+        &[TestCidrV6 {
+            field: "2001:db8::/32",
+            inner: &["::ffff:10.0.0.0/104"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn cidr_v6_invalid() {
+    util::check_fail!(
+        &[TestCidrV6 {
+            field: "10.0.0.0/8",
+            inner: &["2001:db8::/0128", "2001:db8:: /32"],
+        }],
+        &()
+    )
+}
@@ -42,3 +42,54 @@ fn contains_invalid() {
         &()
     )
 }
+
+/// Only implements `validate_contains`, so `char` needles go through its default encoding.
+#[derive(Debug)]
+struct Words(Vec<&'static str>);
+
+impl garde::rules::contains::Contains for Words {
+    fn validate_contains(&self, pat: &str) -> bool {
+        self.0.iter().any(|word| word.contains(pat))
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Char<'a> {
+    #[garde(contains('@'))]
+    field: &'a str,
+
+    #[garde(contains('é'))]
+    multibyte: Option<String>,
+
+    #[garde(contains('ü'))]
+    custom: Words,
+
+    #[garde(inner(contains('@')))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn contains_char_valid() {
+    util::check_ok(
+        &[Char {
+            field: "a@b",
+            multibyte: None,
+            custom: Words(vec!["grün"]),
+            inner: &["@"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn contains_char_invalid() {
+    util::check_fail!(
+        &[Char {
+            field: "ab",
+            multibyte: Some("e".into()),
+            custom: Words(vec!["gruen"]),
+            inner: &["a"]
+        }],
+        &()
+    )
+}
@@ -0,0 +1,32 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(contains_all(["foo", "bar"]))]
+    field: &'a str,
+
+    #[garde(inner(contains_all(["foo", "bar"])))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn contains_all_valid() {
+    util::check_ok(
+        &[Test {
+            field: "_foo_bar_",
+            inner: &["_foo_bar_"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn contains_all_invalid() {
+    util::check_fail!(
+        &[Test {
+            field: "_foo_",
+            inner: &["_foo_"]
+        }],
+        &()
+    )
+}
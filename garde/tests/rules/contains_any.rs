@@ -0,0 +1,32 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(contains_any(["foo", "bar"]))]
+    field: &'a str,
+
+    #[garde(inner(contains_any(["foo", "bar"])))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn contains_any_valid() {
+    util::check_ok(
+        &[Test {
+            field: "_bar_",
+            inner: &["_bar_"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn contains_any_invalid() {
+    util::check_fail!(
+        &[Test {
+            field: "_baz_",
+            inner: &["_baz_"]
+        }],
+        &()
+    )
+}
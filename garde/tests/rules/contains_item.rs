@@ -0,0 +1,71 @@
+use std::collections::BTreeSet;
+
+use super::util;
+
+const ROLE: &str = "admin";
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(contains_item("admin"))]
+    vec: Vec<String>,
+    #[garde(contains_item(ROLE))]
+    slice: &'a [&'a str],
+    #[garde(contains_item(5))]
+    array: [u32; 3],
+    #[garde(contains_item(5))]
+    set: BTreeSet<u32>,
+    #[garde(contains_item("admin"))]
+    option: Option<Vec<&'a str>>,
+    #[garde(inner(contains_item(1)))]
+    inner: Vec<Vec<u8>>,
+}
+
+#[test]
+fn contains_item_valid() {
+    util::check_ok(
+        &[
+            Test {
+                vec: vec!["user".into(), "admin".into()],
+                slice: &["admin"],
+                array: [1, 5, 10],
+                set: BTreeSet::from([5]),
+                option: Some(vec!["admin"]),
+                inner: vec![vec![1], vec![0, 1]],
+            },
+            Test {
+                vec: vec!["admin".into()],
+                slice: &["user", "admin"],
+                array: [5, 5, 5],
+                set: BTreeSet::from([1, 5]),
+                option: None,
+                inner: vec![],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn contains_item_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                vec: vec![],
+                slice: &[],
+                array: [0, 0, 0],
+                set: BTreeSet::new(),
+                option: Some(vec![]),
+                inner: vec![vec![]],
+            },
+            Test {
+                vec: vec!["user".into(), "administrator".into()],
+                slice: &["user"],
+                array: [1, 2, 3],
+                set: BTreeSet::from([1]),
+                option: Some(vec!["user"]),
+                inner: vec![vec![1], vec![2]],
+            }
+        ],
+        &()
+    )
+}
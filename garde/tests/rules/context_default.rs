@@ -0,0 +1,47 @@
+use super::util;
+
+struct Limits {
+    max_len: usize,
+}
+
+impl Limits {
+    fn new() -> Self {
+        Self { max_len: 5 }
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Limits as ctx, default = Limits::new()))]
+struct User<'a> {
+    #[garde(length(max = ctx.max_len))]
+    name: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(C: AsRef<str> as ctx, default = C::default()))]
+struct Prefixed<'a, C: Default> {
+    #[garde(custom(|v: &str, ctx: &C| if v.starts_with(ctx.as_ref()) { Ok(()) } else { Err(garde::Error::new("missing prefix")) }))]
+    value: &'a str,
+    #[garde(skip)]
+    _ctx: std::marker::PhantomData<C>,
+}
+
+#[test]
+fn context_default_valid() {
+    util::check_ok(&[User { name: "alice" }], &Limits { max_len: 5 });
+    User { name: "alice" }.validate_default().unwrap();
+    Prefixed::<String> {
+        value: "anything",
+        _ctx: std::marker::PhantomData,
+    }
+    .validate_default()
+    .unwrap();
+}
+
+#[test]
+fn context_default_invalid() {
+    let report = User { name: "alexander" }.validate_default().unwrap_err();
+    insta::assert_snapshot!(report.to_string());
+    // The default is only used by `validate_default`
+    util::check_ok(&[User { name: "alexander" }], &Limits { max_len: 10 });
+}
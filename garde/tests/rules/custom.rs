@@ -108,3 +108,99 @@ fn multi_custom_invalid() {
         &ctx
     )
 }
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct Method<'a> {
+    #[garde(custom(Self::check_name))]
+    name: &'a str,
+
+    #[garde(inner(custom(Self::check_name)))]
+    inner: &'a [&'a str],
+
+    #[garde(custom(Method::check_name))]
+    by_type_name: &'a str,
+}
+
+impl<'a> Method<'a> {
+    fn check_name(value: &str, ctx: &Context) -> garde::Result {
+        if value != ctx.needle {
+            return Err(garde::Error::new(format!("name is not {}", ctx.needle)));
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn method_custom_valid() {
+    let ctx = Context {
+        needle: "test".into(),
+    };
+    util::check_ok(
+        &[Method {
+            name: "test",
+            inner: &["test"],
+            by_type_name: "test",
+        }],
+        &ctx,
+    )
+}
+
+#[test]
+fn method_custom_invalid() {
+    let ctx = Context {
+        needle: "test".into(),
+    };
+    util::check_fail!(
+        &[Method {
+            name: "asdf",
+            inner: &["asdf"],
+            by_type_name: "asdf",
+        }],
+        &ctx
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct Order<'a> {
+    #[garde(custom(custom_validate_fn), length(min = 10))]
+    custom_first: &'a str,
+
+    #[garde(length(min = 10), custom(custom_validate_fn))]
+    custom_last: &'a str,
+
+    #[garde(inner(custom(custom_validate_fn), length(min = 10)))]
+    inner: &'a [&'a str],
+
+    // `dive` and `inner` run after the other rules, wherever they are written
+    #[garde(dive, length(min = 2))]
+    dive_first: &'a [OrderItem<'a>],
+
+    #[garde(inner(length(min = 10)), length(min = 2))]
+    inner_first: &'a [&'a str],
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct OrderItem<'a> {
+    #[garde(custom(custom_validate_fn))]
+    name: &'a str,
+}
+
+#[test]
+fn order_custom_invalid() {
+    let ctx = Context {
+        needle: "test".into(),
+    };
+    util::check_fail!(
+        &[Order {
+            custom_first: "asdf",
+            custom_last: "asdf",
+            inner: &["asdf"],
+            dive_first: &[OrderItem { name: "asdf" }],
+            inner_first: &["asdf"],
+        }],
+        &ctx
+    )
+}
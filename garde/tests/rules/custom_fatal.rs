@@ -0,0 +1,97 @@
+use super::util;
+
+fn is_number(value: &str, _: &()) -> garde::Result {
+    match value.parse::<u32>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(garde::Error::new("not a number")),
+    }
+}
+
+fn is_even(value: &str, _: &()) -> garde::Result {
+    match value.parse::<u32>() {
+        Ok(v) if v % 2 == 0 => Ok(()),
+        _ => Err(garde::Error::new("not even")),
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Item<'a> {
+    #[garde(length(min = 1))]
+    name: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(custom(is_number, fatal), custom(is_even), length(max = 3))]
+    fatal: &'a str,
+    #[garde(custom(is_number), custom(is_even), length(max = 3))]
+    exhaustive: &'a str,
+    #[garde(inner(custom(is_number, fatal), length(max = 3)))]
+    inner: &'a [&'a str],
+    #[garde(custom(|v: &[Item], _: &()| match v.len() {
+        0 => Err(garde::Error::new("empty")),
+        _ => Ok(()),
+    }, fatal), dive)]
+    dive: &'a [Item<'a>],
+}
+
+#[test]
+fn custom_fatal_valid() {
+    util::check_ok(
+        &[Test {
+            fatal: "12",
+            exhaustive: "12",
+            inner: &["1", "22"],
+            dive: &[Item { name: "a" }],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn custom_fatal_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                fatal: "abcd",
+                exhaustive: "abcd",
+                inner: &["abcd", "1234"],
+                dive: &[],
+            },
+            Test {
+                fatal: "1235",
+                exhaustive: "1235",
+                inner: &[],
+                dive: &[Item { name: "" }],
+            }
+        ],
+        &()
+    )
+}
+
+fn is_non_empty(v: &[Item], _: &()) -> garde::Result {
+    match v.len() {
+        0 => Err(garde::Error::new("empty")),
+        _ => Ok(()),
+    }
+}
+
+// A `fatal` rule which passes doesn't change the order in which the other rules run
+#[derive(Debug, garde::Validate)]
+struct Order<'a> {
+    #[garde(dive, length(max = 1))]
+    exhaustive: &'a [Item<'a>],
+    #[garde(dive, custom(is_non_empty, fatal), length(max = 1))]
+    fatal: &'a [Item<'a>],
+}
+
+#[test]
+fn custom_fatal_order_invalid() {
+    util::check_fail!(
+        &[Order {
+            exhaustive: &[Item { name: "" }, Item { name: "a" }],
+            fatal: &[Item { name: "" }, Item { name: "a" }],
+        }],
+        &()
+    )
+}
@@ -0,0 +1,101 @@
+use garde::error::{Error, Path, Report};
+
+use super::util;
+
+struct Today {
+    month: u32,
+    year: u32,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Today))]
+#[garde(custom_fields(check_card))]
+struct Card<'a> {
+    #[garde(ascii)]
+    number: &'a str,
+    #[garde(length(equal = 5))]
+    expiry: &'a str,
+}
+
+fn check_card(card: &Card, today: &Today) -> Result<(), Report> {
+    let mut report = Report::new();
+    let expiry = card
+        .expiry
+        .split_once('/')
+        .and_then(|(month, year)| Some((month.parse::<u32>().ok()?, year.parse::<u32>().ok()?)));
+    match expiry {
+        Some((month, year)) if (year, month) < (today.year, today.month) => {
+            report.append(Path::new("expiry"), Error::new("card is expired"))
+        }
+        Some(_) => {}
+        None => report.append(Path::empty(), Error::new("expiry is not in `MM/YY` format")),
+    }
+    if report.is_empty() {
+        Ok(())
+    } else {
+        Err(report)
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Today))]
+struct Order<'a> {
+    #[garde(dive)]
+    cards: Vec<Card<'a>>,
+}
+
+#[test]
+fn custom_fields_valid() {
+    let today = Today { month: 6, year: 24 };
+    util::check_ok(
+        &[
+            Card {
+                number: "4111111111111111",
+                expiry: "06/24",
+            },
+            Card {
+                number: "4111111111111111",
+                expiry: "01/25",
+            },
+        ],
+        &today,
+    )
+}
+
+#[test]
+fn custom_fields_invalid() {
+    let today = Today { month: 6, year: 24 };
+    util::check_fail!(
+        &[
+            Card {
+                number: "4111111111111111",
+                expiry: "05/24",
+            },
+            Card {
+                number: "4111111111111111",
+                expiry: "1225",
+            },
+        ],
+        &today
+    )
+}
+
+#[test]
+fn custom_fields_nested_invalid() {
+    let today = Today { month: 6, year: 24 };
+    util::check_fail!(
+        &[Order {
+            cards: vec![
+                Card {
+                    number: "4111111111111111",
+                    expiry: "01/25",
+                },
+                Card {
+                    number: "4111111111111111",
+                    expiry: "12/23",
+                },
+            ],
+        }],
+        &today
+    )
+}
@@ -0,0 +1,109 @@
+use garde::error::{Error, Path, Report};
+
+use super::util;
+
+fn check_date(value: &str, _: &()) -> Result<(), Report> {
+    let mut report = Report::new();
+    let mut parts = value.splitn(3, '-');
+    let (Some(year), Some(month), Some(day)) = (parts.next(), parts.next(), parts.next()) else {
+        report.append(Path::empty(), Error::new("not in `YYYY-MM-DD` format"));
+        return Err(report);
+    };
+    if year.len() != 4 || year.parse::<u32>().is_err() {
+        report.append(Path::new("year"), Error::new("not a valid year"));
+    }
+    if !matches!(month.parse::<u32>(), Ok(1..=12)) {
+        report.append(Path::new("month"), Error::new("not a valid month"));
+    }
+    if !matches!(day.parse::<u32>(), Ok(1..=31)) {
+        report.append(Path::new("day"), Error::new("not a valid day"));
+    }
+    if report.is_empty() {
+        Ok(())
+    } else {
+        Err(report)
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(custom_report(check_date))]
+    field: &'a str,
+    #[garde(inner(custom_report(check_date)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn custom_report_valid() {
+    util::check_ok(
+        &[Test {
+            field: "2024-06-30",
+            inner: &["2024-01-01"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn custom_report_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "24-13-00",
+                inner: &["2024-01-01", "2024-00-32"],
+            },
+            Test {
+                field: "20240630",
+                inner: &["2024"],
+            },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn custom_report_fail_fast() {
+    let value = Test {
+        field: "24-13-00",
+        inner: &["2024-00-32"],
+    };
+    let (path, error) = garde::Validate::validate_first_error(&value, &()).unwrap_err();
+    assert_eq!(path.to_string(), "field.year");
+    assert_eq!(error.message(), "not a valid year");
+}
+
+#[derive(Debug)]
+struct Schedule {
+    slots: Vec<(u32, u32)>,
+}
+
+fn check_slots(value: &Schedule, _: &()) -> Result<(), Report> {
+    let mut report = Report::new();
+    for (index, (start, end)) in value.slots.iter().enumerate() {
+        if start >= end {
+            report.append(
+                Path::new("slots").join(index),
+                Error::new("slot must end after it starts"),
+            );
+        }
+    }
+    report.into_result()
+}
+
+#[derive(Debug, garde::Validate)]
+struct Nested {
+    #[garde(custom_report(check_slots))]
+    schedule: Schedule,
+}
+
+#[test]
+fn custom_report_nested_index_invalid() {
+    util::check_fail!(
+        &[Nested {
+            schedule: Schedule {
+                slots: vec![(1, 2), (2, 3), (3, 4), (5, 4)],
+            },
+        }],
+        &()
+    )
+}
@@ -1,3 +1,4 @@
+use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -50,6 +51,65 @@ fn email_valid() {
     )
 }
 
+#[derive(Debug, garde::Validate)]
+struct Containers<'a> {
+    #[garde(dive)]
+    vec: Vec<Inner<'a>>,
+    #[garde(dive)]
+    option: Option<Inner<'a>>,
+    #[garde(dive)]
+    option_vec: Option<Vec<Inner<'a>>>,
+    #[garde(dive)]
+    boxed_slice: Box<[Inner<'a>]>,
+    #[garde(dive)]
+    hash_map: HashMap<&'a str, Inner<'a>>,
+    #[garde(dive)]
+    btree_map: BTreeMap<String, Vec<Inner<'a>>>,
+}
+
+#[test]
+fn dive_containers_valid() {
+    let inner = Inner { field: "asdf" };
+    util::check_ok(
+        &[
+            Containers {
+                vec: vec![inner, inner],
+                option: Some(inner),
+                option_vec: Some(vec![inner, inner]),
+                boxed_slice: Box::new([inner]),
+                hash_map: HashMap::from([("a", inner)]),
+                btree_map: BTreeMap::from([("a".into(), vec![inner])]),
+            },
+            Containers {
+                vec: vec![],
+                option: None,
+                option_vec: None,
+                boxed_slice: Box::new([]),
+                hash_map: HashMap::new(),
+                btree_map: BTreeMap::new(),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn dive_containers_invalid() {
+    let valid = Inner { field: "asdf" };
+    let inner = Inner { field: "" };
+    util::check_fail!(
+        &[Containers {
+            vec: vec![valid, inner],
+            option: Some(inner),
+            option_vec: Some(vec![valid, inner]),
+            boxed_slice: Box::new([inner]),
+            hash_map: HashMap::from([("a", inner)]),
+            btree_map: BTreeMap::from([("a".into(), vec![valid, inner]), ("b".into(), vec![])]),
+        }],
+        &()
+    )
+}
+
 #[test]
 fn email_invalid() {
     let inner = Inner { field: "" };
@@ -68,3 +128,272 @@ fn email_invalid() {
         &()
     )
 }
+
+struct OuterContext {
+    inner: InnerContext,
+}
+
+struct InnerContext {
+    min: usize,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(InnerContext as ctx))]
+struct WithContext<'a> {
+    #[garde(length(min = ctx.min))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(OuterContext as ctx))]
+struct DiveWithContext<'a> {
+    #[garde(dive(context = ctx.inner))]
+    field: WithContext<'a>,
+    #[garde(dive(context = ctx.inner))]
+    list: Vec<WithContext<'a>>,
+}
+
+#[test]
+fn dive_with_context_valid() {
+    util::check_ok(
+        &[DiveWithContext {
+            field: WithContext { field: "asdf" },
+            list: vec![WithContext { field: "asdf" }],
+        }],
+        &OuterContext {
+            inner: InnerContext { min: 4 },
+        },
+    )
+}
+
+#[test]
+fn dive_with_context_invalid() {
+    util::check_fail!(
+        &[DiveWithContext {
+            field: WithContext { field: "asd" },
+            list: vec![WithContext { field: "asd" }],
+        }],
+        &OuterContext {
+            inner: InnerContext { min: 4 },
+        }
+    )
+}
+
+impl AsRef<InnerContext> for OuterContext {
+    fn as_ref(&self) -> &InnerContext {
+        &self.inner
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(OuterContext))]
+struct DiveAsRef<'a> {
+    #[garde(dive(as_ref))]
+    field: WithContext<'a>,
+    #[garde(inner(dive(as_ref)))]
+    list: Vec<WithContext<'a>>,
+}
+
+#[test]
+fn dive_as_ref_valid() {
+    util::check_ok(
+        &[DiveAsRef {
+            field: WithContext { field: "asdf" },
+            list: vec![WithContext { field: "asdf" }],
+        }],
+        &OuterContext {
+            inner: InnerContext { min: 4 },
+        },
+    )
+}
+
+#[test]
+fn dive_as_ref_invalid() {
+    util::check_fail!(
+        &[DiveAsRef {
+            field: WithContext { field: "asd" },
+            list: vec![WithContext { field: "asdf" }, WithContext { field: "asd" }],
+        }],
+        &OuterContext {
+            inner: InnerContext { min: 4 },
+        }
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Owned {
+    #[garde(length(min = 1))]
+    field: String,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Borrowed<'a> {
+    #[garde(dive)]
+    by_ref: &'a Owned,
+    #[garde(dive)]
+    by_mut: &'a mut Owned,
+    #[garde(dive)]
+    option: Option<&'a Owned>,
+    #[garde(dive)]
+    list: &'a [&'a Owned],
+}
+
+#[test]
+fn dive_borrowed_valid() {
+    let owned = Owned {
+        field: "asdf".into(),
+    };
+    let mut by_mut = Owned {
+        field: "asdf".into(),
+    };
+    util::check_ok(
+        &[Borrowed {
+            by_ref: &owned,
+            by_mut: &mut by_mut,
+            option: Some(&owned),
+            list: &[&owned],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn dive_borrowed_invalid() {
+    let valid = Owned {
+        field: "asdf".into(),
+    };
+    let invalid = Owned { field: "".into() };
+    let mut by_mut = Owned { field: "".into() };
+    util::check_fail!(
+        &[Borrowed {
+            by_ref: &invalid,
+            by_mut: &mut by_mut,
+            option: Some(&invalid),
+            list: &[&valid, &invalid],
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(OuterContext as ctx))]
+struct BorrowedWithContext<'a> {
+    #[garde(dive(context = ctx.inner))]
+    field: &'a WithContext<'a>,
+}
+
+#[test]
+fn dive_borrowed_with_context_invalid() {
+    util::check_fail!(
+        &[BorrowedWithContext {
+            field: &WithContext { field: "asd" },
+        }],
+        &OuterContext {
+            inner: InnerContext { min: 4 },
+        }
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Locked<'a> {
+    #[garde(dive)]
+    ref_cell: std::cell::RefCell<Inner<'a>>,
+    #[garde(dive)]
+    mutex: Arc<std::sync::Mutex<Inner<'a>>>,
+    #[garde(dive)]
+    rw_lock: std::sync::RwLock<Inner<'a>>,
+}
+
+impl<'a> Locked<'a> {
+    fn new(inner: Inner<'a>) -> Self {
+        Self {
+            ref_cell: std::cell::RefCell::new(inner),
+            mutex: Arc::new(std::sync::Mutex::new(inner)),
+            rw_lock: std::sync::RwLock::new(inner),
+        }
+    }
+}
+
+#[test]
+fn dive_locked_valid() {
+    util::check_ok(&[Locked::new(Inner { field: "asdf" })], &())
+}
+
+#[test]
+fn dive_locked_invalid() {
+    util::check_fail!(&[Locked::new(Inner { field: "" })], &())
+}
+
+#[test]
+fn dive_locked_contended() {
+    use garde::Validate;
+
+    let value = Locked::new(Inner { field: "" });
+    let _borrow = value.ref_cell.borrow_mut();
+    let _lock = value.mutex.lock().unwrap();
+    let _write = value.rw_lock.write().unwrap();
+    let report = value.validate().unwrap_err();
+    insta::assert_snapshot!(report.to_string());
+}
+
+#[test]
+fn top_level_slice_valid() {
+    let inner = Inner { field: "asdf" };
+    util::check_ok::<&[Inner]>(&[&[inner, inner], &[]], &())
+}
+
+#[test]
+fn top_level_slice_invalid() {
+    let valid = Inner { field: "asdf" };
+    let invalid = Inner { field: "" };
+    util::check_fail!(&[&[valid, invalid, invalid][..]], &())
+}
+
+trait Item: garde::Validate<Context = ()> + std::fmt::Debug {}
+
+impl Item for Owned {}
+impl Item for Inner<'_> {}
+
+#[derive(Debug, garde::Validate)]
+struct TraitObjects<'a> {
+    #[garde(dive)]
+    items: Vec<Box<dyn Item + 'a>>,
+    #[garde(dive)]
+    by_ref: &'a dyn Item,
+}
+
+#[test]
+fn dive_trait_objects_valid() {
+    let owned = Owned {
+        field: "asdf".into(),
+    };
+    util::check_ok(
+        &[TraitObjects {
+            items: vec![
+                Box::new(Inner { field: "asdf" }),
+                Box::new(Owned {
+                    field: "asdf".into(),
+                }),
+            ],
+            by_ref: &owned,
+        }],
+        &(),
+    );
+}
+
+#[test]
+fn dive_trait_objects_invalid() {
+    let inner = Inner { field: "" };
+    util::check_fail!(
+        &[TraitObjects {
+            items: vec![
+                Box::new(Owned {
+                    field: "asdf".into(),
+                }),
+                Box::new(Owned { field: "".into() }),
+            ],
+            by_ref: &inner,
+        }],
+        &()
+    );
+}
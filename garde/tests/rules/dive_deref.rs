@@ -0,0 +1,59 @@
+use std::ops::Deref;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Inner<'a> {
+    #[garde(length(min = 1))]
+    field: &'a str,
+}
+
+/// Derefs to a type implementing `Validate`, but doesn't implement it itself.
+#[derive(Debug)]
+struct Wrapper<T>(T);
+
+impl<T> Deref for Wrapper<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(dive_deref)]
+    owned: Wrapper<Inner<'a>>,
+    #[garde(dive_deref)]
+    borrowed: &'a Wrapper<Inner<'a>>,
+    #[garde(dive_deref)]
+    boxed: Wrapper<Box<Inner<'a>>>,
+    #[garde(length(min = 1), inner(dive_deref))]
+    inner: Vec<Wrapper<Inner<'a>>>,
+}
+
+#[test]
+fn dive_deref_valid() {
+    util::check_ok(
+        &[Test {
+            owned: Wrapper(Inner { field: "test" }),
+            borrowed: &Wrapper(Inner { field: "test" }),
+            boxed: Wrapper(Box::new(Inner { field: "test" })),
+            inner: vec![Wrapper(Inner { field: "test" })],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn dive_deref_invalid() {
+    util::check_fail!(
+        &[Test {
+            owned: Wrapper(Inner { field: "" }),
+            borrowed: &Wrapper(Inner { field: "" }),
+            boxed: Wrapper(Box::new(Inner { field: "" })),
+            inner: vec![Wrapper(Inner { field: "" })],
+        }],
+        &()
+    )
+}
@@ -0,0 +1,51 @@
+use super::util;
+
+fn is_slug_char(c: char) -> bool {
+    c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-'
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(each_char(is_slug_char))]
+    field: &'a str,
+    #[garde(each_char(|c| c != ' '))]
+    closure: &'a str,
+    #[garde(each_char(char::is_uppercase))]
+    optional: Option<&'a str>,
+    #[garde(inner(each_char(is_slug_char)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn each_char_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "my-slug-1",
+                closure: "no_spaces",
+                optional: Some("ÄB"),
+                inner: &["a", ""],
+            },
+            Test {
+                field: "",
+                closure: "",
+                optional: None,
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn each_char_invalid() {
+    util::check_fail!(
+        &[Test {
+            field: "my_slug",
+            closure: "héllo world",
+            optional: Some("ABc"),
+            inner: &["a", "B"],
+        }],
+        &()
+    )
+}
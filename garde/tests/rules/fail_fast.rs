@@ -0,0 +1,92 @@
+use std::cell::Cell;
+
+use garde::Validate;
+
+#[derive(Debug, Validate)]
+struct Inner {
+    #[garde(range(max = 4))]
+    id: usize,
+}
+
+#[derive(Debug, Validate)]
+struct Test {
+    #[garde(ascii, length(min = 10))]
+    name: String,
+    #[garde(inner(length(min = 1)))]
+    tags: Vec<String>,
+    #[garde(dive)]
+    items: Vec<Inner>,
+}
+
+#[test]
+fn first_error_valid() {
+    let v = Test {
+        name: "abcdefghij".into(),
+        tags: vec!["a".into()],
+        items: vec![Inner { id: 0 }],
+    };
+
+    assert!(v.validate_first_error(&()).is_ok());
+}
+
+#[test]
+fn first_error_invalid() {
+    let v = Test {
+        name: "😂".into(),
+        tags: vec!["".into()],
+        items: vec![Inner { id: 10 }, Inner { id: 20 }],
+    };
+
+    let (path, error) = v.validate_first_error(&()).unwrap_err();
+    assert_eq!(path.to_string(), "items[0].id");
+    assert_eq!(error.message(), "greater than 4");
+
+    let v = Test {
+        name: "😂".into(),
+        tags: vec!["a".into(), "".into(), "".into()],
+        items: vec![],
+    };
+
+    let (path, error) = v.validate_first_error(&()).unwrap_err();
+    assert_eq!(path.to_string(), "name");
+    assert_eq!(error.message(), "not ascii");
+
+    let v = Test {
+        name: "abcdefghij".into(),
+        tags: vec!["a".into(), "".into(), "".into()],
+        items: vec![],
+    };
+
+    let (path, error) = v.validate_first_error(&()).unwrap_err();
+    assert_eq!(path.to_string(), "tags[1]");
+    assert_eq!(error.message(), "length is lower than 1");
+}
+
+#[derive(Debug, Validate)]
+#[garde(context(Cell<usize> as calls))]
+struct Counted {
+    #[garde(custom(count), custom(count))]
+    a: u8,
+    #[garde(custom(count))]
+    b: u8,
+}
+
+fn count(_: &u8, calls: &Cell<usize>) -> garde::Result {
+    calls.set(calls.get() + 1);
+    Err(garde::Error::new("always fails"))
+}
+
+#[test]
+fn first_error_stops_validation() {
+    let v = Counted { a: 0, b: 0 };
+
+    let calls = Cell::new(0);
+    let (path, _) = v.validate_first_error(&calls).unwrap_err();
+    assert_eq!(path.to_string(), "a");
+    assert_eq!(calls.get(), 1);
+
+    let calls = Cell::new(0);
+    let report = v.validate_with(&calls).unwrap_err();
+    assert_eq!(report.iter().count(), 3);
+    assert_eq!(calls.get(), 3);
+}
@@ -0,0 +1,86 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Address<'a> {
+    #[garde(length(min = 1))]
+    street: &'a str,
+    #[garde(length(equal = 5))]
+    zip: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 1))]
+    name: &'a str,
+    #[garde(dive, flatten)]
+    address: Address<'a>,
+    #[garde(flatten, dive)]
+    billing: Option<Address<'a>>,
+}
+
+#[test]
+fn flatten_valid() {
+    util::check_ok(
+        &[
+            Test {
+                name: "test",
+                address: Address {
+                    street: "main",
+                    zip: "12345",
+                },
+                billing: None,
+            },
+            Test {
+                name: "test",
+                address: Address {
+                    street: "main",
+                    zip: "12345",
+                },
+                billing: Some(Address {
+                    street: "side",
+                    zip: "54321",
+                }),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn flatten_invalid() {
+    util::check_fail!(
+        &[Test {
+            name: "",
+            address: Address {
+                street: "",
+                zip: "1234",
+            },
+            billing: Some(Address {
+                street: "",
+                zip: "12345",
+            }),
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+enum Enum<'a> {
+    Struct {
+        #[garde(dive, flatten)]
+        address: Address<'a>,
+    },
+}
+
+#[test]
+fn flatten_enum_invalid() {
+    util::check_fail!(
+        &[Enum::Struct {
+            address: Address {
+                street: "",
+                zip: "12345",
+            },
+        }],
+        &()
+    )
+}
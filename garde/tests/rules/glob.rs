@@ -0,0 +1,51 @@
+use super::util;
+
+const VERSION: &str = "v?.*";
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(glob("*.txt"))]
+    field: &'a str,
+    #[garde(glob(VERSION))]
+    version: &'a str,
+    #[garde(inner(glob("a*b*c")))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn glob_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: ".txt",
+                version: "v1.",
+                inner: &["abc", "aXbYc", "abbcbc"],
+            },
+            Test {
+                field: "dir/ünïcödé.txt",
+                version: "vé.2.3",
+                inner: &["a*b?c"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn glob_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "notes.txt.bak",
+                version: "v.1",
+                inner: &["ab", "abcx"]
+            },
+            Test {
+                field: "txt",
+                version: "v12",
+                inner: &["", "bc"]
+            }
+        ],
+        &()
+    )
+}
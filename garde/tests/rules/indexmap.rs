@@ -0,0 +1,54 @@
+#![cfg(feature = "indexmap")]
+
+use indexmap::{IndexMap, IndexSet};
+
+use super::util;
+
+#[derive(Debug, PartialEq, Eq, Hash, garde::Validate)]
+struct Item<'a> {
+    #[garde(length(min = 1))]
+    name: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 1, max = 2))]
+    length: IndexMap<&'a str, u32>,
+    #[garde(length(min = 1, max = 2))]
+    length_set: IndexSet<u32>,
+    #[garde(dive)]
+    dive: IndexMap<&'a str, Item<'a>>,
+    #[garde(dive)]
+    dive_set: IndexSet<Item<'a>>,
+}
+
+#[test]
+fn indexmap_valid() {
+    util::check_ok(
+        &[Test {
+            length: IndexMap::from([("a", 0), ("b", 1)]),
+            length_set: IndexSet::from([0]),
+            dive: IndexMap::from([("a", Item { name: "a" })]),
+            dive_set: IndexSet::from([Item { name: "a" }]),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn indexmap_invalid() {
+    util::check_fail!(
+        &[Test {
+            length: IndexMap::new(),
+            length_set: IndexSet::from([0, 1, 2]),
+            dive: IndexMap::from([
+                ("z", Item { name: "" }),
+                ("a", Item { name: "a" }),
+                ("m", Item { name: "" }),
+                ("b", Item { name: "" }),
+            ]),
+            dive_set: IndexSet::from([Item { name: "a" }, Item { name: "" }]),
+        }],
+        &()
+    )
+}
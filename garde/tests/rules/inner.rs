@@ -119,3 +119,40 @@ fn alphanumeric_none_valid() {
         &(),
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct OptionalVec {
+    // The outer `inner` applies to the `Vec`, the inner one to each `String`
+    #[garde(length(max = 2), inner(inner(length(max = 10))))]
+    inner: Option<Vec<String>>,
+}
+
+#[test]
+fn optional_vec_valid() {
+    util::check_ok(
+        &[
+            OptionalVec { inner: None },
+            OptionalVec {
+                inner: Some(vec![]),
+            },
+            OptionalVec {
+                inner: Some(vec!["a".into(), "abcdefghij".into()]),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn optional_vec_invalid() {
+    util::check_fail!(
+        &[OptionalVec {
+            inner: Some(vec![
+                "abcdefghijk".into(),
+                "a".into(),
+                "abcdefghijklmnop".into()
+            ]),
+        }],
+        &()
+    )
+}
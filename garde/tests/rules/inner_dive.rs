@@ -0,0 +1,71 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Item<'a> {
+    #[garde(length(min = 1))]
+    name: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 1), inner(dive))]
+    items: Vec<Item<'a>>,
+    #[garde(inner(length(max = 1), inner(dive)))]
+    nested: Vec<Vec<Item<'a>>>,
+    #[garde(inner(tuple(1(dive))))]
+    pairs: Vec<(u32, Item<'a>)>,
+}
+
+#[test]
+fn inner_dive_valid() {
+    util::check_ok(
+        &[Test {
+            items: vec![Item { name: "a" }],
+            nested: vec![vec![Item { name: "b" }], vec![]],
+            pairs: vec![(0, Item { name: "c" })],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn inner_dive_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                items: vec![],
+                nested: vec![],
+                pairs: vec![],
+            },
+            Test {
+                items: vec![Item { name: "a" }, Item { name: "" }],
+                nested: vec![vec![Item { name: "" }, Item { name: "b" }]],
+                pairs: vec![(0, Item { name: "" })],
+            }
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(usize as min))]
+struct WithContext<'a> {
+    #[garde(length(min = *min))]
+    name: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Outer<'a> {
+    #[garde(inner(dive(context = 3)))]
+    items: Vec<WithContext<'a>>,
+}
+
+#[test]
+fn inner_dive_context_invalid() {
+    util::check_fail!(
+        &[Outer {
+            items: vec![WithContext { name: "abc" }, WithContext { name: "ab" }],
+        }],
+        &()
+    )
+}
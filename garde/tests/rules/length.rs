@@ -126,6 +126,33 @@ fn min_max_equal_length_invalid() {
     )
 }
 
+#[derive(Debug, garde::Validate)]
+struct FiveChars<'a> {
+    #[garde(length(chars, min = 5, max = 5))]
+    field: &'a str,
+}
+
+#[test]
+fn min_max_same_bound_valid() {
+    util::check_ok(
+        &[
+            FiveChars { field: "abcde" },
+            FiveChars {
+                field: "😂😂😂😂😂",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn min_max_same_bound_invalid() {
+    util::check_fail!(
+        &[FiveChars { field: "abcd" }, FiveChars { field: "abcdef" }],
+        &()
+    )
+}
+
 #[derive(Debug, garde::Validate)]
 struct SpecialLengthTest<'a> {
     #[garde(length(simple, max = 1))]
@@ -174,3 +201,199 @@ fn char_length_invalid() {
         &()
     )
 }
+
+const MIN_LEN: usize = 2;
+const MAX_LEN: usize = 4;
+
+struct Limits {
+    max: usize,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Limits as ctx))]
+struct ExprBoundsTest<'a> {
+    #[garde(length(min = MIN_LEN, max = MAX_LEN))]
+    consts: &'a str,
+    #[garde(length(chars, min = MIN_LEN + 1, max = ctx.max))]
+    exprs: &'a str,
+    #[garde(inner(length(equal = MAX_LEN)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn expr_bounds_valid() {
+    util::check_ok(
+        &[ExprBoundsTest {
+            consts: "ab",
+            exprs: "ábcde",
+            inner: &["abcd"],
+        }],
+        &Limits { max: 5 },
+    )
+}
+
+#[test]
+fn expr_bounds_invalid() {
+    util::check_fail!(
+        &[
+            ExprBoundsTest {
+                consts: "a",
+                exprs: "áb",
+                inner: &["abc"],
+            },
+            ExprBoundsTest {
+                consts: "abcde",
+                exprs: "ábcdef",
+                inner: &["abcde"],
+            }
+        ],
+        &Limits { max: 5 }
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Multibyte<'a> {
+    // The default mode counts bytes, so "café" has a length of 5.
+    #[garde(length(equal = 5))]
+    simple: &'a str,
+    #[garde(length(chars, equal = 4))]
+    chars: &'a str,
+}
+
+#[test]
+fn multibyte_valid() {
+    util::check_ok(
+        &[Multibyte {
+            simple: "café",
+            chars: "café",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn multibyte_invalid() {
+    util::check_fail!(
+        &[Multibyte {
+            simple: "cafe",
+            chars: "cafés",
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Collections<'a> {
+    #[garde(length(min = 1, max = 2))]
+    hash_map: std::collections::HashMap<&'a str, u32>,
+    #[garde(length(min = 1, max = 2))]
+    btree_map: std::collections::BTreeMap<&'a str, u32>,
+    #[garde(length(min = 1, max = 2))]
+    hash_set: std::collections::HashSet<u32>,
+    #[garde(length(min = 1, max = 2))]
+    btree_set: std::collections::BTreeSet<u32>,
+    #[garde(length(max = 2))]
+    map_ref: &'a std::collections::BTreeMap<&'a str, u32>,
+}
+
+#[test]
+fn collections_valid() {
+    let map = [("a", 1), ("b", 2)].into();
+    util::check_ok(
+        &[Collections {
+            hash_map: [("a", 1)].into(),
+            btree_map: [("a", 1), ("b", 2)].into(),
+            hash_set: [1].into(),
+            btree_set: [1, 2].into(),
+            map_ref: &map,
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn collections_invalid() {
+    let map = [("a", 1), ("b", 2), ("c", 3)].into();
+    util::check_fail!(
+        &[Collections {
+            hash_map: Default::default(),
+            btree_map: [("a", 1), ("b", 2), ("c", 3)].into(),
+            hash_set: Default::default(),
+            btree_set: [1, 2, 3].into(),
+            map_ref: &map,
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Paths<'a> {
+    #[garde(length(min = 1, max = 8))]
+    path_buf: std::path::PathBuf,
+    #[garde(length(max = 8))]
+    path: &'a std::path::Path,
+    #[garde(length(bytes, max = 8))]
+    os_string: std::ffi::OsString,
+    #[garde(length(bytes, min = 1))]
+    os_str: &'a std::ffi::OsStr,
+}
+
+#[test]
+fn paths_valid() {
+    util::check_ok(
+        &[Paths {
+            path_buf: "/tmp/a".into(),
+            path: std::path::Path::new("/tmp/b"),
+            os_string: "café".into(),
+            os_str: std::ffi::OsStr::new("a"),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn paths_invalid() {
+    util::check_fail!(
+        &[Paths {
+            path_buf: std::path::PathBuf::new(),
+            path: std::path::Path::new("/tmp/file.txt"),
+            os_string: "cafécafé".into(),
+            os_str: std::ffi::OsStr::new(""),
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Binary<'a> {
+    #[garde(length(max = 4))]
+    vec: Vec<u8>,
+    #[garde(length(min = 1, max = 4))]
+    slice: &'a [u8],
+    #[garde(length(bytes, max = 4))]
+    boxed: Box<[u8]>,
+}
+
+#[test]
+fn binary_valid() {
+    util::check_ok(
+        &[Binary {
+            vec: vec![0; 4],
+            slice: b"abcd",
+            boxed: Box::new([]),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn binary_invalid() {
+    util::check_fail!(
+        &[Binary {
+            vec: vec![0; 5],
+            slice: b"",
+            boxed: Box::new([1, 2, 3, 4, 5]),
+        }],
+        &()
+    )
+}
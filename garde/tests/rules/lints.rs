@@ -0,0 +1,94 @@
+//! The generated code must not trip clippy in crates which deny its lints.
+#![deny(clippy::all)]
+
+use super::util;
+
+struct Context {
+    strict: bool,
+}
+
+fn is_number(value: &str, _: &Context) -> garde::Result {
+    match value.parse::<u32>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(garde::Error::new("not a number")),
+    }
+}
+
+fn check_whole(_: &Test, _: &Context) -> Result<(), garde::Report> {
+    Ok(())
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context as ctx))]
+struct Inner<'a> {
+    #[garde(length(min = 1))]
+    value: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context as ctx))]
+#[garde(validate = check_whole)]
+struct Test<'a> {
+    #[garde(ascii, length(min = 1, max = 10), custom(is_number))]
+    number: &'a str,
+    #[garde(custom(|v: &str, _: &Context| match v.is_empty() {
+        true => Err(garde::Error::new("empty")),
+        false => Ok(()),
+    }))]
+    closure: &'a str,
+    #[garde(custom(is_number, fatal), length(max = 3))]
+    fatal: &'a str,
+    #[garde(prefix("a"), when = ctx.strict)]
+    guarded: &'a str,
+    #[garde(range(min = 1, max = 10))]
+    range: Option<u32>,
+    #[garde(inner(length(min = 1), each_char(char::is_alphanumeric)))]
+    inner: Vec<&'a str>,
+    #[garde(dive)]
+    dive: Inner<'a>,
+    #[garde(inner(dive))]
+    inner_dive: Option<Inner<'a>>,
+    #[garde(tuple(0(length(min = 1))))]
+    tuple: (&'a str, u32),
+    #[garde(rename("renamed"), alias("old"), length(min = 1))]
+    renamed: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(transparent)]
+struct Newtype<'a>(#[garde(length(min = 1))] &'a str);
+
+#[derive(Debug, garde::Validate)]
+enum Enum<'a> {
+    Struct {
+        #[garde(length(min = 1))]
+        value: &'a str,
+    },
+    Tuple(#[garde(length(min = 1))] &'a str),
+    #[garde(skip)]
+    Skipped,
+}
+
+#[test]
+fn lints_valid() {
+    util::check_ok(
+        &[Test {
+            number: "12",
+            closure: "a",
+            fatal: "123",
+            guarded: "abc",
+            range: Some(5),
+            inner: vec!["a"],
+            dive: Inner { value: "a" },
+            inner_dive: None,
+            tuple: ("a", 0),
+            renamed: "a",
+        }],
+        &Context { strict: true },
+    );
+    util::check_ok(&[Newtype("a")], &());
+    util::check_ok(
+        &[Enum::Struct { value: "a" }, Enum::Tuple("a"), Enum::Skipped],
+        &(),
+    );
+}
@@ -1,27 +1,67 @@
 mod adapt;
+mod alias;
 mod allow_unvalidated;
 mod alphanumeric;
+mod arrayvec;
 mod ascii;
+mod byte_size;
+mod bytes;
+mod catch_unwind;
+mod charset;
+mod cidr;
 mod contains;
+mod contains_all;
+mod contains_any;
+mod contains_item;
+mod context_default;
 mod credit_card;
 mod custom;
+mod custom_fatal;
+mod custom_fields;
+mod custom_report;
 mod dive;
+mod dive_deref;
 mod dive_with_rules;
+mod each_char;
 mod email;
+mod fail_fast;
+mod flatten;
+mod glob;
+mod indexmap;
 mod inner;
+mod inner_dive;
 mod ip;
 mod length;
+mod lints;
 mod matches;
 mod multi_rule;
 mod newtype;
+mod non_zero;
+mod not_pattern;
 mod option;
+mod path;
 mod pattern;
+mod pattern_captures;
 mod phone_number;
 mod prefix;
+mod prefix_any;
 mod range;
+mod recursive;
+mod remote;
+mod report_display;
+mod result;
 mod select;
+mod serde_rename;
 mod skip;
+mod smallvec;
+mod string_like;
 mod suffix;
+mod suffix_any;
+mod tracing;
+mod tuple;
 mod url;
+mod validate_fields;
+mod validate_hook;
+mod when;
 
 mod util;
@@ -61,3 +61,31 @@ fn multi_rule_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Split<'a> {
+    #[garde(prefix("test"))]
+    #[garde(ascii)]
+    #[garde(length(min = 10, max = 100))]
+    field: &'a str,
+}
+
+#[test]
+fn multi_rule_split_valid() {
+    util::check_ok(
+        &[Split {
+            field: "test_test_test",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn multi_rule_split_invalid() {
+    util::check_fail!(
+        &[Split {
+            field: "non-ascii 😂",
+        }],
+        &()
+    )
+}
@@ -63,3 +63,34 @@ fn newtype_fields_invalid() {
         &()
     );
 }
+
+#[derive(Debug, garde::Validate)]
+#[garde(transparent)]
+#[repr(transparent)]
+struct Celsius(#[garde(range(min = -273.15))] f64);
+
+#[derive(Debug, garde::Validate)]
+struct Reading {
+    #[garde(dive)]
+    temperature: Celsius,
+}
+
+#[test]
+fn newtype_numeric_valid() {
+    util::check_ok(&[Celsius(-273.15), Celsius(21.5)], &());
+}
+
+#[test]
+fn newtype_numeric_invalid() {
+    util::check_fail!(&[Celsius(-300.0)], &());
+}
+
+#[test]
+fn newtype_numeric_field_invalid() {
+    util::check_fail!(
+        &[Reading {
+            temperature: Celsius(-300.0),
+        }],
+        &()
+    );
+}
@@ -0,0 +1,59 @@
+use std::num::{NonZeroI32, NonZeroU16, NonZeroU32};
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(range(min = 10, max = 1000))]
+    id: NonZeroU16,
+    #[garde(range(max = 100))]
+    optional: Option<NonZeroU32>,
+    #[garde(inner(range(min = -5, max = 5)))]
+    inner: &'a [NonZeroI32],
+}
+
+fn u16(v: u16) -> NonZeroU16 {
+    NonZeroU16::new(v).unwrap()
+}
+
+fn i32(v: i32) -> NonZeroI32 {
+    NonZeroI32::new(v).unwrap()
+}
+
+#[test]
+fn non_zero_valid() {
+    util::check_ok(
+        &[
+            Test {
+                id: u16(10),
+                optional: None,
+                inner: &[i32(-5), i32(5)],
+            },
+            Test {
+                id: u16(1000),
+                optional: NonZeroU32::new(100),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn non_zero_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                id: u16(9),
+                optional: NonZeroU32::new(101),
+                inner: &[i32(-6)],
+            },
+            Test {
+                id: u16(1001),
+                optional: NonZeroU32::new(u32::MAX),
+                inner: &[i32(1), i32(6)],
+            }
+        ],
+        &()
+    )
+}
@@ -0,0 +1,60 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::util;
+
+static LAZY_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)drop\s+table").unwrap());
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(not_pattern(r"(?i)drop\s+table"))]
+    field: &'a str,
+
+    #[garde(not_pattern(LAZY_RE))]
+    field_path: &'a str,
+
+    #[garde(not_pattern(r"(?i)drop\s+table"))]
+    optional: Option<&'a str>,
+
+    #[garde(inner(not_pattern(r"(?i)drop\s+table")))]
+    inner: &'a [&'a str],
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn not_pattern_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "Robert",
+                field_path: "Robert",
+                optional: Some("Robert"),
+                inner: &["Robert"],
+            },
+            Test {
+                field: "table",
+                field_path: "table",
+                optional: None,
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+#[test]
+fn not_pattern_invalid() {
+    util::check_fail!(
+        &[Test {
+            field: "Robert'); DROP TABLE students;--",
+            field_path: "drop  table",
+            optional: Some("Drop Table"),
+            inner: &["Robert", "drop table"],
+        }],
+        &()
+    )
+}
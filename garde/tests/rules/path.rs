@@ -0,0 +1,40 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(path_component)]
+    field: &'a str,
+    #[garde(inner(path_component))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn path_component_valid() {
+    util::check_ok(
+        &[Test {
+            field: "report.pdf",
+            inner: &[".hidden", "...", "a..b", "with space", "日本語.txt"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn path_component_invalid() {
+    util::check_fail!(
+        &[Test {
+            field: "../etc/passwd",
+            inner: &[
+                "",
+                ".",
+                "..",
+                "/etc",
+                "a/b",
+                "a\\b",
+                "C:\\Windows",
+                "nul\0byte"
+            ],
+        }],
+        &()
+    )
+}
@@ -81,3 +81,95 @@ fn pattern_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Shared<'a> {
+    #[garde(pattern(r"^[a-z]+$"))]
+    a: &'a str,
+    #[garde(pattern(r"^[a-z]+$"))]
+    b: &'a str,
+    #[garde(not_pattern(r"^[a-z]+$"))]
+    c: &'a str,
+    #[garde(pattern(r"^[0-9]+$"))]
+    d: &'a str,
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn pattern_shared_valid() {
+    util::check_ok(
+        &[Shared {
+            a: "abc",
+            b: "def",
+            c: "ABC",
+            d: "123",
+        }],
+        &(),
+    )
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+#[test]
+fn pattern_shared_invalid() {
+    util::check_fail!(
+        &[Shared {
+            a: "ABC",
+            b: "123",
+            c: "abc",
+            d: "abc",
+        }],
+        &()
+    )
+}
+
+const MAX_LEN: usize = 8;
+
+#[derive(Debug, garde::Validate)]
+struct MaxLen<'a> {
+    #[garde(pattern(r"^[a-z]+$", max_len = 4))]
+    literal: &'a str,
+    #[garde(pattern(sub::LAZY_RE, max_len = MAX_LEN))]
+    path: &'a str,
+    #[garde(inner(pattern(r"^[a-z]+$", max_len = 4,)))]
+    inner: &'a [&'a str],
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn pattern_max_len_valid() {
+    util::check_ok(
+        &[MaxLen {
+            literal: "abcd",
+            path: "abcd",
+            inner: &["a", "abcd"],
+        }],
+        &(),
+    )
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+#[test]
+fn pattern_max_len_invalid() {
+    util::check_fail!(
+        &[
+            MaxLen {
+                literal: "abcde",
+                path: "abcd_____",
+                inner: &["abcde", "ABC"],
+            },
+            // within the limit, but not matching
+            MaxLen {
+                literal: "ABC",
+                path: "____",
+                inner: &[],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,77 @@
+#![cfg(not(all(feature = "js-sys", target_arch = "wasm32", target_os = "unknown")))]
+
+use garde::rules::pattern_captures::Captures;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::util;
+
+static DATE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(?P<y>\d{4})-(?P<m>\d{2})$").unwrap());
+
+struct Limits {
+    min_year: u32,
+}
+
+fn check_year(captures: &Captures, limits: &Limits) -> garde::Result {
+    let year: u32 = captures["y"].parse().unwrap();
+    if year < limits.min_year {
+        return Err(garde::Error::new(format!(
+            "year must not be before {}",
+            limits.min_year
+        )));
+    }
+    Ok(())
+}
+
+fn check_month(captures: &Captures, _: &Limits) -> garde::Result {
+    match captures["m"].parse::<u32>().unwrap() {
+        1..=12 => Ok(()),
+        _ => Err(garde::Error::new("month is out of range")),
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Limits))]
+struct Test<'a> {
+    #[garde(pattern_captures(r"^(?P<y>\d{4})-(?P<m>\d{2})$", check_year))]
+    field: &'a str,
+
+    #[garde(pattern_captures(DATE_RE, check_month))]
+    field_path: &'a str,
+
+    #[garde(inner(pattern_captures(r"^(?P<y>\d{4})-(?P<m>\d{2})$", check_year)))]
+    inner: &'a [&'a str],
+}
+
+const LIMITS: Limits = Limits { min_year: 2000 };
+
+#[test]
+fn pattern_captures_valid() {
+    util::check_ok(
+        &[Test {
+            field: "2024-01",
+            field_path: "1999-12",
+            inner: &["2000-99"],
+        }],
+        &LIMITS,
+    )
+}
+
+#[test]
+fn pattern_captures_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "1999-01",
+                field_path: "2024-13",
+                inner: &["2024"],
+            },
+            Test {
+                field: "not a date",
+                field_path: "24-01",
+                inner: &["2024-01", "1000-01"],
+            },
+        ],
+        &LIMITS
+    )
+}
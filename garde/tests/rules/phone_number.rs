@@ -57,3 +57,20 @@ fn phone_number_invalid() {
         &()
     )
 }
+
+#[test]
+fn phone_number_normalize() {
+    use garde::rules::phone_number::{normalize, Region};
+
+    assert_eq!(
+        normalize("+1 (415) 237-0800", None).as_deref(),
+        Some("+14152370800")
+    );
+    assert_eq!(
+        normalize("06 42 92 68 29", Some(Region::FR)).as_deref(),
+        Some("+33642926829")
+    );
+    assert_eq!(normalize("06 42 92 68 29", None), None);
+    assert_eq!(normalize("14152370800", None), None);
+    assert_eq!(normalize("+1 000", None), None);
+}
@@ -0,0 +1,32 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(prefix_any(["+1", "+44"]))]
+    field: &'a str,
+
+    #[garde(inner(prefix_any(["+1", "+44"])))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn prefix_any_valid() {
+    util::check_ok(
+        &[Test {
+            field: "+44 20",
+            inner: &["+44 20"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn prefix_any_invalid() {
+    util::check_fail!(
+        &[Test {
+            field: "+33 1",
+            inner: &["+33 1"]
+        }],
+        &()
+    )
+}
@@ -126,3 +126,420 @@ fn min_max_equal_length_invalid() {
         &()
     )
 }
+
+#[derive(Debug)]
+struct Celsius(i32);
+
+impl Celsius {
+    fn degrees(&self) -> i32 {
+        self.0
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Key<'a> {
+    #[garde(range(min = -10, max = 40, key = Celsius::degrees))]
+    temperature: Celsius,
+    #[garde(inner(range(max = 3, key = str::len)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn key_valid() {
+    util::check_ok(
+        &[
+            Key {
+                temperature: Celsius(-10),
+                inner: &["", "abc"],
+            },
+            Key {
+                temperature: Celsius(40),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn key_invalid() {
+    util::check_fail!(
+        &[
+            Key {
+                temperature: Celsius(-11),
+                inner: &["abcd"]
+            },
+            Key {
+                temperature: Celsius(41),
+                inner: &["abc", "abcde"]
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Prices {
+    #[garde(range(min = 0))]
+    min_price: u64,
+    #[garde(range(min = self.min_price))]
+    max_price: u64,
+    #[garde(range(min = self.min_price, max = self.max_price))]
+    price: u64,
+}
+
+#[test]
+fn range_sibling_field_valid() {
+    util::check_ok(
+        &[
+            Prices {
+                min_price: 10,
+                max_price: 20,
+                price: 15,
+            },
+            Prices {
+                min_price: 10,
+                max_price: 10,
+                price: 10,
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn range_sibling_field_invalid() {
+    util::check_fail!(
+        &[Prices {
+            min_price: 20,
+            max_price: 10,
+            price: 30,
+        }],
+        &()
+    )
+}
+
+#[cfg(feature = "time")]
+mod time_types {
+    use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+    use super::util;
+
+    fn ymd(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    fn hm(hour: u8, minute: u8) -> Time {
+        Time::from_hms(hour, minute, 0).unwrap()
+    }
+
+    #[derive(Debug, garde::Validate)]
+    struct Booking {
+        #[garde(range(min = ymd(2024, Month::January, 1), max = ymd(2024, Month::December, 31)))]
+        date: Date,
+        #[garde(range(min = hm(9, 0), max = hm(17, 0)))]
+        check_in: Time,
+        #[garde(range(max = PrimitiveDateTime::new(self.date, hm(12, 0))))]
+        check_out: Option<PrimitiveDateTime>,
+    }
+
+    #[test]
+    fn range_time_valid() {
+        util::check_ok(
+            &[
+                Booking {
+                    date: ymd(2024, Month::January, 1),
+                    check_in: hm(9, 0),
+                    check_out: None,
+                },
+                Booking {
+                    date: ymd(2024, Month::December, 31),
+                    check_in: hm(17, 0),
+                    check_out: Some(PrimitiveDateTime::new(
+                        ymd(2024, Month::December, 31),
+                        hm(12, 0),
+                    )),
+                },
+            ],
+            &(),
+        )
+    }
+
+    #[test]
+    fn range_time_invalid() {
+        util::check_fail!(
+            &[
+                Booking {
+                    date: ymd(2023, Month::December, 31),
+                    check_in: hm(8, 59),
+                    check_out: Some(PrimitiveDateTime::new(
+                        ymd(2023, Month::December, 31),
+                        hm(12, 1),
+                    )),
+                },
+                Booking {
+                    date: ymd(2025, Month::January, 1),
+                    check_in: hm(17, 1),
+                    check_out: None,
+                },
+            ],
+            &()
+        )
+    }
+
+    fn with_offset(date: Date, time: Time, offset_hours: i8) -> OffsetDateTime {
+        PrimitiveDateTime::new(date, time)
+            .assume_offset(UtcOffset::from_hms(offset_hours, 0, 0).unwrap())
+    }
+
+    #[derive(Debug, garde::Validate)]
+    struct Event {
+        #[garde(range(
+            min = with_offset(ymd(2024, Month::January, 1), hm(0, 0), 0),
+            max = with_offset(ymd(2024, Month::December, 31), hm(23, 59), 0),
+        ))]
+        at: OffsetDateTime,
+    }
+
+    #[test]
+    fn range_offset_date_time_valid() {
+        util::check_ok(
+            &[
+                Event {
+                    at: with_offset(ymd(2024, Month::January, 1), hm(0, 0), 0),
+                },
+                // 2024-01-01 00:00 UTC
+                Event {
+                    at: with_offset(ymd(2024, Month::January, 1), hm(2, 0), 2),
+                },
+                // 2024-12-31 23:59 UTC
+                Event {
+                    at: with_offset(ymd(2024, Month::December, 31), hm(20, 59), -3),
+                },
+            ],
+            &(),
+        )
+    }
+
+    #[test]
+    fn range_offset_date_time_invalid() {
+        util::check_fail!(
+            &[
+                // 2023-12-31 23:00 UTC
+                Event {
+                    at: with_offset(ymd(2024, Month::January, 1), hm(1, 0), 2),
+                },
+                // 2025-01-01 00:59 UTC
+                Event {
+                    at: with_offset(ymd(2024, Month::December, 31), hm(21, 59), -3),
+                },
+            ],
+            &()
+        )
+    }
+}
+
+/// `MAX` is lower than most values, which must not matter if `max` is omitted.
+#[derive(Debug, PartialEq, PartialOrd)]
+struct Count(u32);
+
+impl garde::rules::range::Bounds for Count {
+    type Size = u32;
+
+    const MIN: Self::Size = 0;
+    const MAX: Self::Size = 0;
+
+    fn validate_bounds(
+        &self,
+        lower_bound: Self::Size,
+        upper_bound: Self::Size,
+    ) -> Result<(), garde::rules::range::OutOfBounds> {
+        if self.0 < lower_bound {
+            Err(garde::rules::range::OutOfBounds::Lower)
+        } else if self.0 > upper_bound {
+            Err(garde::rules::range::OutOfBounds::Upper)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct HalfOpen {
+    #[garde(range(max = 0.))]
+    max_only: f64,
+    #[garde(range(min = 0.))]
+    min_only: f64,
+    #[garde(range(min = 2))]
+    custom_min_only: Count,
+    #[garde(range(min = 1, max = 10))]
+    non_zero: std::num::NonZeroU8,
+    #[garde(range(max = -1))]
+    non_zero_signed: Option<std::num::NonZeroI64>,
+}
+
+#[test]
+fn half_open_valid() {
+    util::check_ok(
+        &[HalfOpen {
+            max_only: f64::NEG_INFINITY,
+            min_only: f64::INFINITY,
+            custom_min_only: Count(100),
+            non_zero: std::num::NonZeroU8::new(10).unwrap(),
+            non_zero_signed: None,
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn half_open_invalid() {
+    util::check_fail!(
+        &[HalfOpen {
+            max_only: f64::INFINITY,
+            min_only: f64::NEG_INFINITY,
+            custom_min_only: Count(1),
+            non_zero: std::num::NonZeroU8::new(11).unwrap(),
+            non_zero_signed: std::num::NonZeroI64::new(1),
+        }],
+        &()
+    )
+}
+
+trait Limits {
+    const MIN: u32;
+    const MAX: u32;
+}
+
+#[derive(Debug, garde::Validate)]
+struct Config {
+    #[garde(range(min = Config::MIN, max = Config::MAX))]
+    workers: u32,
+    #[garde(range(max = <Self as Limits>::MAX))]
+    queue: u32,
+    #[garde(range(min = Config::MIN_TIMEOUT, max = u64::MAX / 2))]
+    timeout: u64,
+}
+
+impl Config {
+    const MIN_TIMEOUT: u64 = 5;
+}
+
+impl Limits for Config {
+    const MIN: u32 = 1;
+    const MAX: u32 = 100;
+}
+
+#[test]
+fn range_const_path_valid() {
+    util::check_ok(
+        &[Config {
+            workers: 100,
+            queue: 0,
+            timeout: 5,
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn range_const_path_invalid() {
+    util::check_fail!(
+        &[Config {
+            workers: 0,
+            queue: 101,
+            timeout: 4,
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Params {
+    #[garde(range(min = 0, max = 100))]
+    both: u64,
+    #[garde(range(min = 2))]
+    custom: Count,
+    #[garde(range(equal = 40))]
+    equal: Option<u64>,
+}
+
+#[test]
+fn range_error_params() {
+    use garde::Validate as _;
+
+    let report = Params {
+        both: 150,
+        custom: Count(1),
+        equal: Some(41),
+    }
+    .validate()
+    .unwrap_err();
+    let params = report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.params().collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        params,
+        [
+            (
+                "both".to_string(),
+                vec![("min", "0"), ("max", "100"), ("value", "150")]
+            ),
+            // `Count` doesn't report its value
+            ("custom".to_string(), vec![("min", "2")]),
+            (
+                "equal".to_string(),
+                vec![("min", "40"), ("max", "40"), ("value", "41")]
+            ),
+        ]
+    );
+}
+
+#[derive(Debug, garde::Validate)]
+struct Char<'a> {
+    #[garde(range(min = 'a', max = 'z'))]
+    lowercase: char,
+    #[garde(range(max = '~'))]
+    ascii: Option<char>,
+    #[garde(inner(range(min = '0', max = '9')))]
+    digits: &'a [char],
+}
+
+#[test]
+fn range_char_valid() {
+    util::check_ok(
+        &[
+            Char {
+                lowercase: 'a',
+                ascii: None,
+                digits: &['0', '9'],
+            },
+            Char {
+                lowercase: 'z',
+                ascii: Some('\0'),
+                digits: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn range_char_invalid() {
+    util::check_fail!(
+        &[
+            Char {
+                lowercase: 'A',
+                ascii: Some('é'),
+                digits: &['a', '5'],
+            },
+            Char {
+                lowercase: '{',
+                ascii: Some('\u{80}'),
+                digits: &['/'],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,63 @@
+use super::util;
+
+#[allow(clippy::vec_box)]
+#[derive(Debug, garde::Validate)]
+struct Tree<'a> {
+    #[garde(length(min = 1))]
+    name: &'a str,
+    #[garde(dive)]
+    children: Vec<Box<Tree<'a>>>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Node {
+    #[garde(range(min = 0))]
+    value: i32,
+    #[garde(dive)]
+    next: Option<Box<Node>>,
+}
+
+fn tree<'a>(name: &'a str, children: Vec<Tree<'a>>) -> Tree<'a> {
+    Tree {
+        name,
+        children: children.into_iter().map(Box::new).collect(),
+    }
+}
+
+fn list(values: &[i32]) -> Option<Box<Node>> {
+    values
+        .iter()
+        .rev()
+        .fold(None, |next, &value| Some(Box::new(Node { value, next })))
+}
+
+#[test]
+fn recursive_valid() {
+    util::check_ok(
+        &[
+            tree("root", vec![]),
+            tree(
+                "root",
+                vec![tree("a", vec![tree("b", vec![])]), tree("c", vec![])],
+            ),
+        ],
+        &(),
+    );
+    util::check_ok(&[*list(&[0, 1, 2]).unwrap()], &());
+}
+
+#[test]
+fn recursive_invalid() {
+    util::check_fail!(
+        &[tree(
+            "",
+            vec![tree("a", vec![tree("", vec![])]), tree("", vec![])]
+        )],
+        &()
+    );
+}
+
+#[test]
+fn recursive_list_invalid() {
+    util::check_fail!(&[*list(&[0, -1, 2, -3]).unwrap()], &());
+}
@@ -0,0 +1,88 @@
+use super::util;
+
+mod other {
+    #[derive(Debug)]
+    pub struct User {
+        pub name: String,
+        pub tags: Vec<String>,
+    }
+
+    #[derive(Debug)]
+    pub enum Id {
+        Name(String),
+        Number { value: u32 },
+    }
+}
+
+#[allow(dead_code)]
+#[derive(garde::Validate)]
+#[garde(remote = "other::User")]
+struct UserDef {
+    #[garde(length(min = 1))]
+    name: String,
+    #[garde(inner(ascii))]
+    tags: Vec<String>,
+}
+
+struct Limits {
+    max: u32,
+}
+
+#[allow(dead_code)]
+#[derive(garde::Validate)]
+#[garde(remote = "other::Id")]
+#[garde(context(Limits as ctx))]
+enum IdDef {
+    Name(#[garde(alphanumeric)] String),
+    Number {
+        #[garde(range(max = ctx.max))]
+        value: u32,
+    },
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Limits))]
+struct Test {
+    #[garde(custom_report(|v: &other::User, _: &Limits| UserDef::validate_remote(v, &())))]
+    user: other::User,
+    #[garde(custom_report(IdDef::validate_remote))]
+    id: other::Id,
+}
+
+#[test]
+fn remote_valid() {
+    util::check_ok(
+        &[Test {
+            user: other::User {
+                name: "a".into(),
+                tags: vec!["b".into()],
+            },
+            id: other::Id::Number { value: 10 },
+        }],
+        &Limits { max: 10 },
+    );
+    assert!(IdDef::validate_remote(&other::Id::Name("abc".into()), &Limits { max: 0 }).is_ok());
+}
+
+#[test]
+fn remote_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                user: other::User {
+                    name: "".into(),
+                    tags: vec!["b".into(), "😂".into()],
+                },
+                id: other::Id::Number { value: 11 },
+            },
+            Test {
+                user: other::User {
+                    name: "a".into(),
+                    tags: vec![],
+                },
+                id: other::Id::Name("a-b".into()),
+            }
+        ],
+        &Limits { max: 10 }
+    )
+}
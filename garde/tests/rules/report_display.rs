@@ -0,0 +1,76 @@
+use garde::Validate;
+
+#[derive(Debug, garde::Validate)]
+struct User<'a> {
+    #[garde(length(min = 1), ascii)]
+    name: &'a str,
+    #[garde(inner(email))]
+    emails: &'a [&'a str],
+    #[garde(dive)]
+    address: Address<'a>,
+    #[garde(dive)]
+    nickname: Nickname<'a>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Address<'a> {
+    #[garde(length(min = 1))]
+    street: &'a str,
+    #[garde(length(min = 1))]
+    city: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(transparent)]
+struct Nickname<'a>(#[garde(length(min = 1))] &'a str);
+
+fn invalid() -> User<'static> {
+    User {
+        name: "",
+        emails: &["email@here.com", "invalid.com", "also.invalid"],
+        address: Address {
+            street: "",
+            city: "",
+        },
+        nickname: Nickname(""),
+    }
+}
+
+#[test]
+fn report_display_flat() {
+    let report = invalid().validate().unwrap_err();
+    insta::assert_snapshot!(format!("{report}"));
+}
+
+#[test]
+fn report_display_tree() {
+    let report = invalid().validate().unwrap_err();
+    insta::assert_snapshot!(format!("{report:#}"));
+}
+
+#[test]
+fn report_eq_expected() {
+    use garde::{Error, Path, Report};
+
+    let mut expected = Report::new();
+    expected.append(Path::new("nickname"), Error::new("length is lower than 1"));
+    expected.append(
+        Path::new("address").join("city"),
+        Error::new("length is lower than 1"),
+    );
+    expected.append(
+        Path::new("address").join("street"),
+        Error::new("length is lower than 1"),
+    );
+    expected.append(
+        Path::new("emails").join(2usize),
+        Error::new("not a valid email: value is missing `@`"),
+    );
+    expected.append(
+        Path::new("emails").join(1usize),
+        Error::new("not a valid email: value is missing `@`"),
+    );
+    expected.append(Path::new("name"), Error::new("length is lower than 1"));
+
+    assert_eq!(invalid().validate().unwrap_err(), expected);
+}
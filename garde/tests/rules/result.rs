@@ -0,0 +1,61 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Success<'a> {
+    #[garde(length(min = 1))]
+    value: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Failure<'a> {
+    #[garde(ascii)]
+    reason: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(dive)]
+    both: Result<Success<'a>, Failure<'a>>,
+    #[garde(inner(dive))]
+    ok_only: Result<Success<'a>, Failure<'a>>,
+    #[garde(inner(length(min = 1)))]
+    rules: Result<&'a str, u32>,
+}
+
+#[test]
+fn result_valid() {
+    util::check_ok(
+        &[
+            Test {
+                both: Ok(Success { value: "a" }),
+                ok_only: Ok(Success { value: "a" }),
+                rules: Ok("a"),
+            },
+            Test {
+                both: Err(Failure { reason: "a" }),
+                ok_only: Err(Failure { reason: "😂" }),
+                rules: Err(0),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn result_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                both: Ok(Success { value: "" }),
+                ok_only: Ok(Success { value: "" }),
+                rules: Ok(""),
+            },
+            Test {
+                both: Err(Failure { reason: "😂" }),
+                ok_only: Err(Failure { reason: "😂" }),
+                rules: Err(0),
+            }
+        ],
+        &()
+    )
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "serde")]
+
+use serde::Deserialize;
+
+use super::util;
+
+#[derive(Debug, Deserialize, garde::Validate)]
+#[serde(rename_all = "camelCase")]
+struct Test<'a> {
+    #[garde(length(min = 1))]
+    first_name: &'a str,
+    #[serde(rename = "surname")]
+    #[garde(length(min = 1))]
+    last_name: &'a str,
+    #[serde(rename(serialize = "ignored", deserialize = "mail"))]
+    #[garde(length(min = 1))]
+    email_address: &'a str,
+    #[serde(rename = "ignored")]
+    #[garde(rename("phone"), length(min = 1))]
+    phone_number: &'a str,
+    #[serde(default)]
+    #[garde(length(min = 1))]
+    r#type: &'a str,
+}
+
+#[derive(Debug, Deserialize, garde::Validate)]
+#[serde(rename_all_fields = "SCREAMING-KEBAB-CASE")]
+enum Enum<'a> {
+    Struct {
+        #[garde(length(min = 1))]
+        field_name: &'a str,
+    },
+    #[serde(rename_all = "PascalCase")]
+    Other {
+        #[garde(length(min = 1))]
+        field_name: &'a str,
+    },
+    Tuple(#[garde(length(min = 1))] &'a str),
+}
+
+#[test]
+fn serde_rename_invalid() {
+    util::check_fail!(
+        &[Test {
+            first_name: "",
+            last_name: "",
+            email_address: "",
+            phone_number: "",
+            r#type: "",
+        }],
+        &()
+    );
+}
+
+#[test]
+fn serde_rename_enum_invalid() {
+    util::check_fail!(
+        &[
+            Enum::Struct { field_name: "" },
+            Enum::Other { field_name: "" },
+            Enum::Tuple("")
+        ],
+        &()
+    );
+}
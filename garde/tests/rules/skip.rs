@@ -21,9 +21,115 @@ enum Enum {
     Tuple(#[garde(skip)] u64),
 }
 
+#[allow(dead_code)]
+#[derive(Debug, garde::Validate)]
+enum SkipVariant {
+    #[garde(skip)]
+    Struct {
+        field: u64,
+    },
+    #[garde(skip)]
+    Tuple(u64, String),
+    #[garde(skip)]
+    Unit,
+    Validated(#[garde(range(max = 10))] u64),
+}
+
 #[test]
 fn skip_valid() {
     util::check_ok(&[Struct { field: 50 }], &());
     util::check_ok(&[Tuple(50)], &());
     util::check_ok(&[Enum::Struct { field: 50 }, Enum::Tuple(50)], &());
+    util::check_ok(
+        &[
+            SkipVariant::Struct { field: 50 },
+            SkipVariant::Tuple(50, String::new()),
+            SkipVariant::Unit,
+            SkipVariant::Validated(5),
+        ],
+        &(),
+    );
+}
+
+#[test]
+fn skip_variant_invalid() {
+    util::check_fail!(&[SkipVariant::Validated(50)], &());
+}
+
+#[allow(dead_code)]
+#[derive(Debug, garde::Validate)]
+enum Mixed<'a> {
+    Struct {
+        #[garde(skip)]
+        skipped: u64,
+        #[garde(length(min = 1))]
+        validated: &'a str,
+        #[garde(skip)]
+        also_skipped: &'a str,
+    },
+    Tuple(
+        #[garde(skip)] u64,
+        #[garde(length(min = 1))] &'a str,
+        #[garde(skip)] &'a str,
+        #[garde(range(max = 10))] u64,
+    ),
+}
+
+#[allow(dead_code)]
+#[derive(Debug, garde::Validate)]
+struct MixedTuple<'a>(
+    #[garde(skip)] u64,
+    #[garde(length(min = 1))] &'a str,
+    #[garde(skip)] &'a str,
+);
+
+#[test]
+fn skip_mixed_valid() {
+    util::check_ok(
+        &[
+            Mixed::Struct {
+                skipped: 0,
+                validated: "a",
+                also_skipped: "",
+            },
+            Mixed::Tuple(50, "a", "", 10),
+        ],
+        &(),
+    );
+    util::check_ok(&[MixedTuple(0, "a", "")], &());
+}
+
+#[test]
+fn skip_mixed_invalid() {
+    util::check_fail!(
+        &[
+            Mixed::Struct {
+                skipped: 0,
+                validated: "",
+                also_skipped: "",
+            },
+            Mixed::Tuple(50, "", "", 11),
+        ],
+        &()
+    );
+}
+
+#[test]
+fn skip_mixed_tuple_struct_invalid() {
+    util::check_fail!(&[MixedTuple(0, "", "")], &());
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(skip_in_release)]
+struct SkipInRelease {
+    #[garde(range(max = 10))]
+    field: u64,
+}
+
+#[test]
+fn skip_in_release() {
+    use garde::Validate;
+
+    let value = SkipInRelease { field: 50 };
+    assert_eq!(value.validate().is_err(), cfg!(debug_assertions));
 }
@@ -0,0 +1,49 @@
+#![cfg(feature = "smallvec")]
+
+use smallvec::{smallvec, SmallVec};
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Item<'a> {
+    #[garde(length(min = 1))]
+    name: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 1, max = 2))]
+    length: SmallVec<[u32; 2]>,
+    #[garde(dive)]
+    dive: SmallVec<[Item<'a>; 2]>,
+    #[garde(inner(length(min = 1)))]
+    inner: SmallVec<[&'a str; 2]>,
+    #[garde(contains_item(1))]
+    contains_item: SmallVec<[u32; 4]>,
+}
+
+#[test]
+fn smallvec_valid() {
+    util::check_ok(
+        &[Test {
+            length: smallvec![1, 2],
+            dive: smallvec![Item { name: "a" }],
+            inner: smallvec!["a", "b", "c"],
+            contains_item: smallvec![0, 1],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn smallvec_invalid() {
+    util::check_fail!(
+        &[Test {
+            length: smallvec![1, 2, 3],
+            dive: smallvec![Item { name: "a" }, Item { name: "" }],
+            inner: smallvec!["a", ""],
+            contains_item: smallvec![],
+        }],
+        &()
+    )
+}
@@ -0,0 +1,93 @@
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(email)]
+    cow: Cow<'a, str>,
+    #[garde(ascii, length(min = 1))]
+    boxed: Box<str>,
+    #[garde(prefix("test"))]
+    rc: Rc<str>,
+    #[garde(contains("test"))]
+    arc: Arc<str>,
+    #[garde(inner(email))]
+    inner: &'a [Cow<'a, str>],
+}
+
+#[test]
+fn string_like_valid() {
+    util::check_ok(
+        &[Test {
+            cow: Cow::Borrowed("email@here.com"),
+            boxed: "test".into(),
+            rc: "test_".into(),
+            arc: "_test_".into(),
+            inner: &[Cow::Owned("email@here.com".to_string())],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn string_like_invalid() {
+    util::check_fail!(
+        &[Test {
+            cow: Cow::Owned("invalid.com".to_string()),
+            boxed: "\u{1f602}".into(),
+            rc: "_test".into(),
+            arc: "_____".into(),
+            inner: &[Cow::Borrowed("invalid.com")],
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Shared {
+    #[garde(email)]
+    email: Arc<str>,
+    #[garde(email, length(max = 20))]
+    rc_email: Rc<str>,
+    #[garde(ascii)]
+    arc_string: Arc<String>,
+    #[garde(length(min = 1, max = 2))]
+    items: Arc<[u32]>,
+    #[garde(range(min = 1, max = 10))]
+    count: Rc<u32>,
+    #[garde(range(max = 10))]
+    optional: Option<Arc<u8>>,
+}
+
+#[test]
+fn shared_valid() {
+    util::check_ok(
+        &[Shared {
+            email: "email@here.com".into(),
+            rc_email: "email@here.com".into(),
+            arc_string: Arc::new("test".to_string()),
+            items: Arc::new([1, 2]),
+            count: Rc::new(10),
+            optional: None,
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn shared_invalid() {
+    util::check_fail!(
+        &[Shared {
+            email: "invalid.com".into(),
+            rc_email: "long.email.address@here.com".into(),
+            arc_string: Arc::new("\u{1f602}".to_string()),
+            items: Arc::new([]),
+            count: Rc::new(0),
+            optional: Some(Arc::new(11)),
+        }],
+        &()
+    )
+}
@@ -0,0 +1,32 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(suffix_any([".com", ".org"]))]
+    field: &'a str,
+
+    #[garde(inner(suffix_any([".com", ".org"])))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn suffix_any_valid() {
+    util::check_ok(
+        &[Test {
+            field: "test.org",
+            inner: &["test.org"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn suffix_any_invalid() {
+    util::check_fail!(
+        &[Test {
+            field: "test.net",
+            inner: &["test.net"]
+        }],
+        &()
+    )
+}
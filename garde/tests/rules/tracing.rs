@@ -0,0 +1,73 @@
+#![cfg(feature = "tracing")]
+
+use std::sync::{Arc, Mutex};
+
+use garde::external::tracing;
+use garde::Validate;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Records the name and fields of every span which is created.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+struct Fields(String);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0 += &format!(" {}={:?}", field.name(), value);
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = Fields(span.metadata().name().to_string());
+        span.record(&mut fields);
+        let mut spans = self.0.lock().unwrap();
+        spans.push(fields.0);
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, _: &Event<'_>) {}
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+fn is_number(value: &str, _: &()) -> garde::Result {
+    match value.parse::<u32>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(garde::Error::new("not a number")),
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(custom(is_number), length(max = 3))]
+    field: &'a str,
+    #[garde(inner(ascii))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn tracing_spans() {
+    let recorder = Recorder::default();
+    let test = Test {
+        field: "1",
+        inner: &["a"],
+    };
+    tracing::subscriber::with_default(recorder.clone(), || test.validate().unwrap());
+
+    let spans = recorder.0.lock().unwrap();
+    insta::assert_snapshot!(spans.join("\n"));
+}
@@ -0,0 +1,35 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(tuple(0(length(min = 1)), 1(range(max = 100))))]
+    pair: (&'a str, u32),
+    #[garde(tuple(1(ascii, length(max = 3))))]
+    partial: (u32, &'a str, u32),
+    #[garde(inner(tuple(0(alphanumeric), 1(inner(range(min = 1))))))]
+    inner: &'a [(&'a str, Vec<u8>)],
+}
+
+#[test]
+fn tuple_valid() {
+    util::check_ok(
+        &[Test {
+            pair: ("a", 100),
+            partial: (0, "abc", 0),
+            inner: &[("a1", vec![1, 2]), ("b", vec![])],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn tuple_invalid() {
+    util::check_fail!(
+        &[Test {
+            pair: ("", 101),
+            partial: (0, "😂", 0),
+            inner: &[("a1", vec![1]), ("-", vec![1, 0])],
+        }],
+        &()
+    )
+}
@@ -109,3 +109,111 @@ fn url_valid_wrapper() {
     };
     println!("{:?}", value.validate().unwrap_err());
 }
+
+#[derive(Debug, Validate)]
+struct RequireHost<'a> {
+    #[garde(url(require_host))]
+    field: &'a str,
+    #[garde(inner(url(require_host)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn url_require_host_valid() {
+    util::check_ok(
+        &[RequireHost {
+            field: "https://www.youtube.com/watch?v=dQw4w9WgXcQ",
+            inner: &["http://info.cern.ch/hypertext/WWW/TheProject.html"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn url_require_host_invalid() {
+    util::check_fail!(
+        &[
+            RequireHost {
+                field: "mailto:user@example.com",
+                inner: &["data:text/plain,hello"],
+            },
+            RequireHost {
+                field: "asdf",
+                inner: &["https://example.com", "urn:isbn:0451450523"],
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, Validate)]
+struct Https<'a> {
+    #[garde(url(https))]
+    field: &'a str,
+    #[garde(inner(url(https)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn url_https_valid() {
+    util::check_ok(
+        &[Https {
+            field: "https://www.youtube.com/watch?v=dQw4w9WgXcQ",
+            inner: &["HTTPS://example.com/"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn url_https_invalid() {
+    util::check_fail!(
+        &[
+            Https {
+                field: "http://example.com",
+                inner: &["ftp://example.com", "mailto:user@example.com"],
+            },
+            Https {
+                field: "asdf",
+                inner: &["https://example.com", "https-like://example.com"],
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, Validate)]
+struct HttpsRequireHost<'a> {
+    #[garde(url(https, require_host))]
+    field: &'a str,
+    #[garde(inner(url(require_host, https)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn url_https_require_host_valid() {
+    util::check_ok(
+        &[HttpsRequireHost {
+            field: "https://www.youtube.com/watch?v=dQw4w9WgXcQ",
+            inner: &["https://example.com/"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn url_https_require_host_invalid() {
+    util::check_fail!(
+        &[
+            HttpsRequireHost {
+                field: "http://example.com",
+                inner: &["ftp://example.com", "mailto:user@example.com"],
+            },
+            HttpsRequireHost {
+                field: "asdf",
+                inner: &["https://example.com", "data:text/plain,hello"],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,57 @@
+#[derive(Debug, garde::Validate)]
+#[garde(validate_fields)]
+struct Patch<'a> {
+    #[garde(email)]
+    email: &'a str,
+    #[garde(length(min = 1))]
+    name: &'a str,
+    #[garde(rename("userAge"), range(min = 18))]
+    age: u8,
+    #[garde(dive)]
+    address: Address<'a>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Address<'a> {
+    #[garde(length(min = 1))]
+    city: &'a str,
+}
+
+const INVALID: Patch<'static> = Patch {
+    email: "not an email",
+    name: "",
+    age: 5,
+    address: Address { city: "" },
+};
+
+#[test]
+fn validate_fields_valid() {
+    let patch = Patch {
+        email: "test@mail.com",
+        ..INVALID
+    };
+    patch.validate_fields(&(), &["email"]).unwrap();
+    patch.validate_fields(&(), &[]).unwrap();
+    assert!(garde::Validate::validate(&patch).is_err());
+}
+
+#[test]
+fn validate_fields_invalid() {
+    let report = INVALID
+        .validate_fields(&(), &["name", "userAge", "address"])
+        .unwrap_err();
+    insta::assert_snapshot!(report.to_string());
+}
+
+#[test]
+fn validate_fields_unknown() {
+    let patch = Patch {
+        email: "test@mail.com",
+        ..INVALID
+    };
+    // Fields are matched by the name they are reported under
+    let report = patch
+        .validate_fields(&(), &["email", "age", "emial"])
+        .unwrap_err();
+    insta::assert_snapshot!(report.to_string());
+}
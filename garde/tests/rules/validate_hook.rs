@@ -0,0 +1,161 @@
+use garde::error::{Error, Path, Report};
+
+use super::util;
+
+struct Limits {
+    max_total: u32,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Limits))]
+#[garde(validate = check_order)]
+#[garde(validate = self::check_total)]
+#[garde(custom_fields(check_total))]
+struct Booking {
+    #[garde(range(min = 1))]
+    adults: u32,
+    #[garde(skip)]
+    children: u32,
+}
+
+fn check_order(value: &Booking, _: &Limits) -> Result<(), Report> {
+    if value.children > 0 && value.adults == 0 {
+        let mut report = Report::new();
+        report.append(
+            Path::new("children"),
+            Error::new("children must be accompanied by an adult"),
+        );
+        return Err(report);
+    }
+    Ok(())
+}
+
+fn check_total(value: &Booking, limits: &Limits) -> Result<(), Report> {
+    if value.adults + value.children > limits.max_total {
+        let mut report = Report::new();
+        report.append(
+            Path::empty(),
+            Error::new(format!("more than {} guests", limits.max_total)),
+        );
+        return Err(report);
+    }
+    Ok(())
+}
+
+#[test]
+fn validate_hook_valid() {
+    util::check_ok(
+        &[
+            Booking {
+                adults: 1,
+                children: 2,
+            },
+            Booking {
+                adults: 4,
+                children: 0,
+            },
+        ],
+        &Limits { max_total: 4 },
+    )
+}
+
+#[test]
+fn validate_hook_invalid() {
+    util::check_fail!(
+        &[
+            Booking {
+                adults: 0,
+                children: 2,
+            },
+            Booking {
+                adults: 3,
+                children: 2,
+            },
+        ],
+        &Limits { max_total: 4 }
+    )
+}
+
+#[test]
+fn validate_hook_fail_fast() {
+    let booking = Booking {
+        adults: 3,
+        children: 2,
+    };
+    let (path, error) =
+        garde::Validate::validate_first_error(&booking, &Limits { max_total: 4 }).unwrap_err();
+    assert!(path.is_empty());
+    assert_eq!(error.message(), "more than 4 guests");
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Limits))]
+enum Room {
+    #[garde(validate = check_beds)]
+    Shared {
+        #[garde(range(min = 1))]
+        beds: u32,
+        #[garde(skip)]
+        guests: u32,
+    },
+    #[garde(validate = check_private)]
+    #[garde(validate = check_private)]
+    Private(#[garde(skip)] u32),
+    #[garde(validate = check_closed)]
+    Closed,
+}
+
+fn check_beds(value: &Room, _: &Limits) -> Result<(), Report> {
+    let mut report = Report::new();
+    if let Room::Shared { beds, guests } = value {
+        if guests > beds {
+            report.append(Path::new("guests"), Error::new("more guests than beds"));
+        }
+    }
+    match report.is_empty() {
+        true => Ok(()),
+        false => Err(report),
+    }
+}
+
+fn check_private(value: &Room, limits: &Limits) -> Result<(), Report> {
+    let mut report = Report::new();
+    if let Room::Private(guests) = value {
+        if *guests > limits.max_total {
+            report.append(
+                Path::empty(),
+                Error::new(format!("more than {} guests", limits.max_total)),
+            );
+        }
+    }
+    match report.is_empty() {
+        true => Ok(()),
+        false => Err(report),
+    }
+}
+
+fn check_closed(_: &Room, _: &Limits) -> Result<(), Report> {
+    let mut report = Report::new();
+    report.append(Path::empty(), Error::new("room is closed"));
+    Err(report)
+}
+
+#[test]
+fn validate_hook_variant_valid() {
+    util::check_ok(
+        &[Room::Shared { beds: 2, guests: 2 }, Room::Private(4)],
+        &Limits { max_total: 4 },
+    )
+}
+
+#[test]
+fn validate_hook_variant_invalid() {
+    util::check_fail!(
+        &[
+            Room::Shared { beds: 0, guests: 1 },
+            Room::Private(5),
+            Room::Closed,
+        ],
+        &Limits { max_total: 4 }
+    )
+}
@@ -0,0 +1,110 @@
+use super::util;
+
+struct Context {
+    strict: bool,
+}
+
+fn is_number(value: &str, _: &Context) -> garde::Result {
+    match value.parse::<u32>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(garde::Error::new("not a number")),
+    }
+}
+
+fn is_not_empty(value: &str, _: &Context) -> Result<(), garde::Report> {
+    let mut report = garde::Report::new();
+    if value.is_empty() {
+        report.append(garde::Path::new("value"), garde::Error::new("empty"));
+    }
+    match report.is_empty() {
+        true => Ok(()),
+        false => Err(report),
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context as ctx))]
+struct Test<'a> {
+    #[garde(length(min = 1))]
+    #[garde(ascii, prefix("a"), when = ctx.strict)]
+    rule: &'a str,
+    #[garde(custom(is_number), when = ctx.strict)]
+    custom: &'a str,
+    #[garde(custom_report(is_not_empty), when = ctx.strict)]
+    custom_report: &'a str,
+    #[garde(custom(is_number, fatal), when = ctx.strict)]
+    #[garde(length(max = 3))]
+    fatal: &'a str,
+    #[garde(length(max = 3), when = !self.rule.is_empty())]
+    field: &'a str,
+}
+
+#[test]
+fn when_valid() {
+    util::check_ok(
+        &[
+            Test {
+                rule: "abc",
+                custom: "12",
+                custom_report: "a",
+                fatal: "12",
+                field: "",
+            },
+            Test {
+                rule: "abc",
+                custom: "12",
+                custom_report: "a",
+                fatal: "12",
+                field: "abc",
+            },
+        ],
+        &Context { strict: true },
+    );
+    util::check_ok(
+        &[Test {
+            rule: "ábcd",
+            custom: "a",
+            custom_report: "",
+            fatal: "a",
+            field: "abc",
+        }],
+        &Context { strict: false },
+    )
+}
+
+#[test]
+fn when_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                rule: "",
+                custom: "a",
+                custom_report: "",
+                fatal: "abcd",
+                field: "abcd",
+            },
+            Test {
+                rule: "ábcd",
+                custom: "a",
+                custom_report: "",
+                fatal: "abcd",
+                field: "abcd",
+            }
+        ],
+        &Context { strict: true }
+    );
+}
+
+#[test]
+fn when_invalid_disabled() {
+    util::check_fail!(
+        &[Test {
+            rule: "",
+            custom: "a",
+            custom_report: "",
+            fatal: "abcd",
+            field: "abcd",
+        }],
+        &Context { strict: false }
+    );
+}
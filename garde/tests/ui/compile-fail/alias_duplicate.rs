@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(alias("old"), alias("old"), ascii)]
+    field: &'a str,
+    #[garde(inner(alias("old"), ascii))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
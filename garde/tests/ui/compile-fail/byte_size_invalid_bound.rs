@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(byte_size(max = "10 parsecs"))]
+    field: &'a str,
+    #[garde(byte_size(min = "10MB", max = "1KB"))]
+    reversed: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(contains_any([]))]
+    empty_list: String,
+    #[garde(contains_all(["foo", ""]))]
+    empty_string: String,
+}
+
+fn main() {}
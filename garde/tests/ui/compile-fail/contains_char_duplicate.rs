@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(contains("a"), contains('b'))]
+    a: &'a str,
+    #[garde(contains('a'), contains("b"))]
+    b: &'a str,
+}
+
+fn main() {}
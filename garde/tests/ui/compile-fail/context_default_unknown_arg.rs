@@ -0,0 +1,12 @@
+#![allow(dead_code)]
+
+struct Limits;
+
+#[derive(garde::Validate)]
+#[garde(context(Limits, fallback = Limits))]
+struct Test<'a> {
+    #[garde(ascii)]
+    field: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+fn check(_: &str, _: &()) -> garde::Result {
+    Ok(())
+}
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(custom(check, fatl))]
+    field: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#![allow(dead_code)]
+
+fn check_name(value: &str) -> garde::Result {
+    let _ = value;
+    Ok(())
+}
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(custom(check_name))]
+    field: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+fn check_name(value: &str, _: &()) -> bool {
+    !value.is_empty()
+}
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(custom(check_name))]
+    field: &'a str,
+}
+
+fn main() {}
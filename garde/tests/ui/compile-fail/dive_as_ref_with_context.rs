@@ -0,0 +1,15 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Inner {
+    #[garde(length(min = 1))]
+    field: String,
+}
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(dive(as_ref, context = ()))]
+    field: Inner,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+#[garde(context(u32))]
+struct Inner {
+    #[garde(skip)]
+    field: u32,
+}
+
+#[derive(garde::Validate)]
+#[garde(context(String as ctx))]
+struct Test {
+    #[garde(dive(context = ctx))]
+    field: Inner,
+}
+
+fn main() {}
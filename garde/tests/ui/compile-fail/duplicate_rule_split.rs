@@ -0,0 +1,10 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(ascii, length(min = 1))]
+    #[garde(ascii)]
+    field: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Inner {
+    #[garde(skip)]
+    a: u32,
+}
+
+#[derive(garde::Validate)]
+struct Test(#[garde(dive, flatten)] Inner);
+
+fn main() {}
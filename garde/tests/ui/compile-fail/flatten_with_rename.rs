@@ -0,0 +1,17 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Inner<'a> {
+    #[garde(length(min = 1))]
+    a: &'a str,
+}
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(dive, flatten, rename("b"))]
+    a: Inner<'a>,
+    #[garde(alias("d"), dive, flatten)]
+    c: Inner<'a>,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(flatten)]
+    a: &'a str,
+    #[garde(dive, flatten, length(min = 1))]
+    b: Vec<&'a str>,
+}
+
+fn main() {}
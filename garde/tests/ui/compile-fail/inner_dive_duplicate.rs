@@ -0,0 +1,15 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Item {
+    #[garde(skip)]
+    value: u32,
+}
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(inner(dive, dive))]
+    items: Vec<Item>,
+}
+
+fn main() {}
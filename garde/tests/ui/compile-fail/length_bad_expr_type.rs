@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+const MIN_LEN: u32 = 1;
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = MIN_LEN, max = "50"))]
+    field: &'a str,
+}
+
+fn main() {}
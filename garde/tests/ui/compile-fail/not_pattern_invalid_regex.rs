@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(not_pattern("("))]
+    field: &'a str,
+}
+
+fn main() {}
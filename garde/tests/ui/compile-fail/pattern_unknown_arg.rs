@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(pattern(r"^abcd|efgh$", max_length = 10))]
+    field: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(prefix_any([]))]
+    empty_list: String,
+    #[garde(suffix_any([".com", ""]))]
+    empty_string: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(range(equal = 1, min = 0))]
+    a: u64,
+    #[garde(range(max = 2, equal = 1))]
+    b: u64,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+enum Prices {
+    Struct {
+        #[garde(skip)]
+        min_price: u64,
+        #[garde(range(min = self.min_price))]
+        max_price: u64,
+    },
+    Tuple(#[garde(skip)] u64, #[garde(inner(range(max = self.0)))] Vec<u64>),
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#![allow(dead_code)]
+
+mod other {
+    pub struct User {
+        pub name: String,
+    }
+}
+
+#[derive(garde::Validate)]
+#[garde(remote = "other::User")]
+struct UserDef {
+    #[garde(length(min = 1))]
+    username: String,
+}
+
+fn main() {}
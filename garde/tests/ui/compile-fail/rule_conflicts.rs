@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(skip, ascii)]
+    a: &'a str,
+    #[garde(ascii, skip)]
+    b: &'a str,
+    #[garde(inner(length(min = 1)), dive)]
+    c: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(tuple(0(ascii), 0(length(min = 1))))]
+    field: (&'a str, u32),
+}
+
+fn main() {}
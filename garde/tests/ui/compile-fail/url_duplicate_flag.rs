@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(url(https, https))]
+    field: &'a str,
+}
+
+fn main() {}
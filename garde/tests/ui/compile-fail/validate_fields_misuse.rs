@@ -0,0 +1,15 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+#[garde(validate_fields)]
+struct Tuple<'a>(#[garde(length(min = 1))] &'a str);
+
+#[derive(garde::Validate)]
+#[garde(validate_fields)]
+#[garde(transparent)]
+struct Transparent<'a> {
+    #[garde(length(min = 1))]
+    a: &'a str,
+}
+
+fn main() {}
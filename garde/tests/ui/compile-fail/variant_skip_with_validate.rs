@@ -0,0 +1,14 @@
+#![allow(dead_code)]
+
+fn check(_: &Test, _: &()) -> Result<(), garde::Report> {
+    Ok(())
+}
+
+#[derive(garde::Validate)]
+enum Test {
+    #[garde(skip)]
+    #[garde(validate = check)]
+    Unit,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+enum Test {
+    #[garde(ascii)]
+    Tuple(String),
+    #[garde(skip)]
+    #[garde(skip)]
+    Unit,
+}
+
+fn main() {}
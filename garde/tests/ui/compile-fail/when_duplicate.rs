@@ -0,0 +1,14 @@
+#![allow(dead_code)]
+
+struct Context {
+    strict: bool,
+}
+
+#[derive(garde::Validate)]
+#[garde(context(Context as ctx))]
+struct Test<'a> {
+    #[garde(ascii, when = ctx.strict, when = !ctx.strict)]
+    field: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+
+struct Context {
+    strict: bool,
+}
+
+#[derive(garde::Validate)]
+#[garde(context(Context as ctx))]
+struct Test<'a> {
+    #[garde(skip, when = ctx.strict)]
+    skip: &'a str,
+    #[garde(dive, when = ctx.strict)]
+    dive: &'a [Inner<'a>],
+    #[garde(inner(ascii, when = ctx.strict))]
+    inner: &'a [&'a str],
+}
+
+#[derive(garde::Validate)]
+struct Inner<'a> {
+    #[garde(ascii)]
+    field: &'a str,
+}
+
+fn main() {}
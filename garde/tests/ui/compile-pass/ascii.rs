@@ -6,6 +6,10 @@ struct Test<'a> {
     field: &'a str,
     #[garde(inner(ascii))]
     inner: &'a [&'a str],
+    #[garde(ascii(printable))]
+    printable: &'a str,
+    #[garde(inner(ascii(printable)))]
+    inner_printable: &'a [&'a str],
 }
 
 fn main() {}
@@ -0,0 +1,15 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(byte_size(min = "1KB", max = "10MB"))]
+    field: &'a str,
+    #[garde(byte_size(max = "1.5GiB"))]
+    field2: String,
+    #[garde(byte_size(equal = "512B"))]
+    field3: Option<String>,
+    #[garde(inner(byte_size(min = "1 kib")))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(contains_all(["foo", "bar"]))]
+    field: &'a str,
+    #[garde(inner(contains_all(["foo"])))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(contains_any(["foo", "bar"]))]
+    field: &'a str,
+    #[garde(inner(contains_any(["foo"])))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -62,4 +62,32 @@ struct Baz {
     value: u32,
 }
 
+#[derive(garde::Validate)]
+struct Generic<T: AsRef<str>> {
+    #[garde(custom(Self::check))]
+    value: T,
+}
+
+impl<T: AsRef<str>> Generic<T> {
+    fn check(value: &T, _: &()) -> garde::Result {
+        let _ = value.as_ref();
+        Ok(())
+    }
+}
+
+#[derive(garde::Validate)]
+enum Enum {
+    Struct {
+        #[garde(custom(Self::check))]
+        value: String,
+    },
+    Tuple(#[garde(inner(custom(Self::check)))] Vec<String>),
+}
+
+impl Enum {
+    fn check(_: &str, _: &()) -> garde::Result {
+        Ok(())
+    }
+}
+
 fn main() {}
@@ -0,0 +1,32 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(custom_report(custom_validate_fn))]
+    a: &'a str,
+    #[garde(custom_report(|_, _| Ok(())))]
+    b: &'a str,
+    #[garde(inner(custom_report(custom_validate_fn)))]
+    inner_a: &'a [&'a str],
+    #[garde(custom(single_error_fn), custom_report(custom_validate_fn))]
+    c: &'a str,
+}
+
+fn custom_validate_fn(_: &str, _: &()) -> Result<(), garde::Report> {
+    unimplemented!()
+}
+
+fn single_error_fn(_: &str, _: &()) -> Result<(), garde::Error> {
+    unimplemented!()
+}
+
+#[derive(garde::Validate)]
+enum Enum<'a> {
+    Struct {
+        #[garde(custom_report(custom_validate_fn))]
+        field: &'a str,
+    },
+    Tuple(#[garde(custom_report(custom_validate_fn))] &'a str),
+}
+
+fn main() {}
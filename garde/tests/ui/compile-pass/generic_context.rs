@@ -0,0 +1,46 @@
+#![allow(dead_code)]
+
+trait Limits {
+    fn max_len(&self) -> usize;
+}
+
+#[derive(garde::Validate)]
+#[garde(context(C: Limits))]
+struct Bounded<C> {
+    #[garde(custom(check_len))]
+    name: String,
+    #[garde(skip)]
+    _marker: std::marker::PhantomData<C>,
+}
+
+fn check_len<C: Limits>(value: &str, ctx: &C) -> garde::Result {
+    if value.len() > ctx.max_len() {
+        return Err(garde::Error::new("too long"));
+    }
+    Ok(())
+}
+
+#[derive(garde::Validate)]
+#[garde(context(C: Limits + Send + 'static as ctx))]
+enum Generic<'a, C>
+where
+    C: Sync,
+{
+    Struct {
+        #[garde(length(max = ctx.max_len()))]
+        name: &'a str,
+        #[garde(dive)]
+        inner: Bounded<C>,
+    },
+}
+
+#[derive(garde::Validate)]
+#[garde(context(C))]
+struct Unbounded<C: Limits> {
+    #[garde(custom(check_len))]
+    name: String,
+    #[garde(skip)]
+    _marker: std::marker::PhantomData<C>,
+}
+
+fn main() {}
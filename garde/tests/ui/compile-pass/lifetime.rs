@@ -0,0 +1,49 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Borrowed<'a> {
+    #[garde(length(max = 5))]
+    name: &'a str,
+    #[garde(inner(ascii))]
+    tags: &'a [&'a str],
+}
+
+#[derive(garde::Validate)]
+struct Nested<'a, 'b: 'a> {
+    #[garde(dive)]
+    inner: &'a Borrowed<'b>,
+    #[garde(length(min = 1))]
+    name: std::borrow::Cow<'b, str>,
+}
+
+#[derive(garde::Validate)]
+#[garde(context(Ctx))]
+struct WithContext<'a, T>
+where
+    T: AsRef<str>,
+{
+    #[garde(custom(check_value))]
+    value: &'a T,
+}
+
+struct Ctx {
+    max: usize,
+}
+
+fn check_value<T: AsRef<str>>(value: &&T, ctx: &Ctx) -> garde::Result {
+    if value.as_ref().len() > ctx.max {
+        return Err(garde::Error::new("too long"));
+    }
+    Ok(())
+}
+
+#[derive(garde::Validate)]
+enum Borrowing<'a> {
+    Struct {
+        #[garde(length(max = 5))]
+        name: &'a str,
+    },
+    Tuple(#[garde(dive)] Borrowed<'a>),
+}
+
+fn main() {}
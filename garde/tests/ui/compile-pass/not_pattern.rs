@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(not_pattern(r"a|b"))]
+    field: &'a str,
+    #[garde(inner(not_pattern(r"a|b")))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(prefix_any(["+1", "+44"]))]
+    field: &'a str,
+    #[garde(inner(prefix_any(["+1", "+44"])))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -6,6 +6,30 @@ struct Test<'a> {
     field: u64,
     #[garde(inner(range(min = 10, max = 100)))]
     inner: &'a [u64],
+    #[garde(range(min = 1, max = 10, key = str::len))]
+    key: &'a str,
+}
+
+#[derive(garde::Validate)]
+struct Prices {
+    #[garde(skip)]
+    min_price: u64,
+    #[garde(range(min = self.min_price))]
+    max_price: u64,
+}
+
+#[derive(garde::Validate)]
+enum Limited {
+    Struct {
+        #[garde(range(max = self.limit()))]
+        value: u64,
+    },
+}
+
+impl Limited {
+    fn limit(&self) -> u64 {
+        100
+    }
 }
 
 fn main() {}
@@ -6,4 +6,13 @@ struct Test<'a> {
     field: &'a str,
 }
 
+#[derive(garde::Validate)]
+enum Enum<'a> {
+    #[garde(skip)]
+    Struct { field: &'a str },
+    #[garde(skip)]
+    Tuple(&'a str),
+    Unit,
+}
+
 fn main() {}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(suffix_any([".com", ".org"]))]
+    field: &'a str,
+    #[garde(inner(suffix_any([".com", ".org"])))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(tuple(0(length(min = 1)), 1(range(max = 100))))]
+    pair: (&'a str, u32),
+    #[garde(tuple(0(custom(custom_validate_fn)), 1(inner(length(min = 1)))))]
+    nested: (&'a str, Vec<&'a str>),
+    #[garde(inner(tuple(1(ascii))))]
+    inner: &'a [(u32, &'a str)],
+}
+
+fn custom_validate_fn(_: &str, _: &()) -> Result<(), garde::Error> {
+    unimplemented!()
+}
+
+#[derive(garde::Validate)]
+struct Tuple<'a>(#[garde(tuple(0(ascii)))] (&'a str,));
+
+fn main() {}
@@ -1,6 +1,7 @@
 use std::collections::BTreeSet;
 
-use proc_macro2::Span;
+use proc_macro2::{Delimiter, Span, TokenStream as TokenStream2, TokenTree};
+use quote::ToTokens;
 use syn::parse_quote;
 use syn::spanned::Spanned;
 
@@ -11,7 +12,7 @@ use crate::util::{default_ctx_name, MaybeFoldError};
 pub fn check(input: model::Input) -> syn::Result<model::Validate> {
     let model::Input {
         ident,
-        generics,
+        mut generics,
         attrs,
         kind,
     } = input;
@@ -22,16 +23,33 @@ pub fn check(input: model::Input) -> syn::Result<model::Validate> {
         error.maybe_fold(e);
     }
 
-    let context = match get_context(&attrs) {
-        Ok(v) => v,
+    let (context, context_default) = match get_context(&attrs) {
+        Ok(model::RawContext {
+            ty,
+            bounds,
+            ident,
+            default,
+        }) => {
+            if !bounds.is_empty() {
+                generics
+                    .make_where_clause()
+                    .predicates
+                    .push(parse_quote!(#ty: #(#bounds)+*));
+            }
+            ((ty, ident), default)
+        }
         Err(e) => {
             error.maybe_fold(e);
-            (parse_quote!(()), default_ctx_name())
+            ((parse_quote!(()), default_ctx_name()), None)
         }
     };
 
+    let custom_fields = get_custom_fields(&attrs);
+
     let transparent = get_transparent_attr(&attrs);
 
+    let remote = get_remote(&attrs);
+
     let options = get_options(&attrs);
 
     let kind = match kind {
@@ -48,13 +66,19 @@ pub fn check(input: model::Input) -> syn::Result<model::Validate> {
         model::InputKind::Enum(list) => {
             let mut inner_error = None;
             let mut variants = Vec::new();
-            for (ident, variant) in list {
+            for (ident, variant, hooks) in list {
+                if let Some(variant) = &variant {
+                    if let Err(e) = check_no_self_field_access(variant) {
+                        inner_error.maybe_fold(e);
+                        continue;
+                    }
+                }
                 match variant {
                     Some(variant) => match check_variant(variant, &options) {
-                        Ok(v) => variants.push((ident, Some(v))),
+                        Ok(v) => variants.push((ident, Some(v), hooks)),
                         Err(e) => inner_error.maybe_fold(e),
                     },
-                    None => variants.push((ident, None)),
+                    None => variants.push((ident, None, hooks)),
                 }
             }
             if let Some(inner_error) = inner_error {
@@ -73,6 +97,23 @@ pub fn check(input: model::Input) -> syn::Result<model::Validate> {
         }
     }
 
+    if let Some(span) = get_validate_fields_attr(&attrs) {
+        if !matches!(
+            kind,
+            model::ValidateKind::Struct(model::ValidateVariant::Struct(_))
+        ) {
+            error.maybe_fold(syn::Error::new(
+                span,
+                "`validate_fields` may only be used on structs with named fields",
+            ));
+        } else if transparent.is_some() || remote.is_some() {
+            error.maybe_fold(syn::Error::new(
+                span,
+                "`validate_fields` may not be combined with `transparent` or `remote`",
+            ));
+        }
+    }
+
     if let Some(error) = error {
         return Err(error);
     }
@@ -81,7 +122,10 @@ pub fn check(input: model::Input) -> syn::Result<model::Validate> {
         ident,
         generics,
         context,
+        context_default,
+        custom_fields,
         is_transparent: transparent.is_some(),
+        remote,
         kind,
         options,
     })
@@ -92,6 +136,9 @@ fn check_attrs(attrs: &[(Span, model::Attr)]) -> syn::Result<()> {
 
     let mut set = BTreeSet::new();
     for (span, attr) in attrs {
+        if let model::Attr::CustomFields(..) | model::Attr::Validate(..) = attr {
+            continue;
+        }
         let d = attr.discriminant();
         if set.contains(&d) {
             error.maybe_fold(syn::Error::new(
@@ -108,7 +155,7 @@ fn check_attrs(attrs: &[(Span, model::Attr)]) -> syn::Result<()> {
     }
 }
 
-fn get_context(attrs: &[(Span, model::Attr)]) -> syn::Result<(syn::Type, syn::Ident)> {
+fn get_context(attrs: &[(Span, model::Attr)]) -> syn::Result<model::RawContext> {
     #![allow(clippy::single_match)]
 
     let error = None;
@@ -116,7 +163,7 @@ fn get_context(attrs: &[(Span, model::Attr)]) -> syn::Result<(syn::Type, syn::Id
 
     for (_, attr) in attrs {
         match attr {
-            model::Attr::Context(ty, ident) => context = Some((ty, ident)),
+            model::Attr::Context(raw) => context = Some(raw),
             _ => {}
         }
     }
@@ -126,9 +173,29 @@ fn get_context(attrs: &[(Span, model::Attr)]) -> syn::Result<(syn::Type, syn::Id
     }
 
     match context {
-        Some((ty, id)) => Ok(((**ty).clone(), (*id).clone())),
-        None => Ok((parse_quote!(()), default_ctx_name())),
+        Some(raw) => Ok((**raw).clone()),
+        None => Ok(model::RawContext {
+            ty: parse_quote!(()),
+            bounds: Vec::new(),
+            ident: default_ctx_name(),
+            default: None,
+        }),
+    }
+}
+
+fn get_custom_fields(attrs: &[(Span, model::Attr)]) -> Vec<syn::Expr> {
+    let mut custom_fields = Vec::new();
+
+    for (_, attr) in attrs {
+        match attr {
+            model::Attr::CustomFields(expr) | model::Attr::Validate(expr) => {
+                custom_fields.push((**expr).clone())
+            }
+            _ => {}
+        }
     }
+
+    custom_fields
 }
 
 fn get_transparent_attr(attrs: &[(Span, model::Attr)]) -> Option<Span> {
@@ -141,6 +208,26 @@ fn get_transparent_attr(attrs: &[(Span, model::Attr)]) -> Option<Span> {
     None
 }
 
+fn get_validate_fields_attr(attrs: &[(Span, model::Attr)]) -> Option<Span> {
+    for (span, attr) in attrs {
+        if let model::Attr::ValidateFields = attr {
+            return Some(*span);
+        }
+    }
+
+    None
+}
+
+fn get_remote(attrs: &[(Span, model::Attr)]) -> Option<syn::Type> {
+    for (_, attr) in attrs {
+        if let model::Attr::Remote(ty) = attr {
+            return Some((**ty).clone());
+        }
+    }
+
+    None
+}
+
 fn is_unary_struct(k: &model::ValidateKind) -> bool {
     match k {
         model::ValidateKind::Struct(model::ValidateVariant::Tuple(fields)) => {
@@ -160,12 +247,18 @@ fn is_unary_struct(k: &model::ValidateKind) -> bool {
 fn get_options(attrs: &[(Span, model::Attr)]) -> model::Options {
     let mut options = model::Options {
         allow_unvalidated: false,
+        skip_in_release: false,
+        catch_unwind: false,
+        validate_fields: false,
     };
 
     for (_, attr) in attrs {
         match attr {
             model::Attr::Context(..) => {}
             model::Attr::AllowUnvalidated => options.allow_unvalidated = true,
+            model::Attr::SkipInRelease => options.skip_in_release = true,
+            model::Attr::CatchUnwind => options.catch_unwind = true,
+            model::Attr::ValidateFields => options.validate_fields = true,
             _ => {}
         }
     }
@@ -204,6 +297,12 @@ fn check_variant(
                         continue;
                     }
                 };
+                if let Some(span) = field.flatten {
+                    error.maybe_fold(syn::Error::new(
+                        span,
+                        "`flatten` may only be used on named fields",
+                    ));
+                }
                 fields.push(field);
             }
             model::ValidateVariant::Tuple(fields)
@@ -217,22 +316,107 @@ fn check_variant(
     Ok(variant)
 }
 
+/// In enums, `self` refers to the enum itself, so the fields of the variant
+/// can't be accessed through it. This is checked for `range` bounds, which
+/// are commonly used to compare against a sibling field in structs.
+fn check_no_self_field_access(variant: &model::Variant) -> syn::Result<()> {
+    fn check_rules(rules: &[model::RawRule], error: &mut Option<syn::Error>) {
+        for rule in rules {
+            match &rule.kind {
+                model::RawRuleKind::Range(v) => {
+                    let bounds = [&v.range.min, &v.range.max, &v.range.equal];
+                    for expr in bounds.into_iter().flatten() {
+                        if let Some(span) = find_self_field_access(expr.to_token_stream()) {
+                            error.maybe_fold(syn::Error::new(
+                                span,
+                                "fields can't be accessed through `self` in enum variants",
+                            ));
+                        }
+                    }
+                }
+                model::RawRuleKind::Inner(v) => check_rules(&v.contents, error),
+                _ => {}
+            }
+        }
+    }
+
+    let mut error = None;
+
+    match variant {
+        model::Variant::Struct(map) => {
+            for field in map.values() {
+                check_rules(&field.rules, &mut error);
+            }
+        }
+        model::Variant::Tuple(list) => {
+            for field in list {
+                check_rules(&field.rules, &mut error);
+            }
+        }
+    }
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// Returns the span of the first `self.<field>` in `tokens`.
+/// Method calls such as `self.method()` are not field accesses.
+fn find_self_field_access(tokens: TokenStream2) -> Option<Span> {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            TokenTree::Group(group) => {
+                if let Some(span) = find_self_field_access(group.stream()) {
+                    return Some(span);
+                }
+            }
+            TokenTree::Ident(ident) if ident == "self" => {
+                let is_field_access = match &tokens[i + 1..] {
+                    // `self.method(...)` or `self.method::<T>(...)`
+                    [TokenTree::Punct(dot), _, TokenTree::Group(args), ..]
+                        if dot.as_char() == '.' && args.delimiter() == Delimiter::Parenthesis =>
+                    {
+                        false
+                    }
+                    [TokenTree::Punct(dot), _, TokenTree::Punct(colon), ..]
+                        if dot.as_char() == '.' && colon.as_char() == ':' =>
+                    {
+                        false
+                    }
+                    [TokenTree::Punct(dot), _, ..] => dot.as_char() == '.',
+                    _ => false,
+                };
+                if is_field_access {
+                    return Some(ident.span());
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
 fn check_field(field: model::Field, options: &model::Options) -> syn::Result<model::ValidateField> {
     let mut error = None;
 
     let model::Field {
         ty,
         rules: raw_rules,
+        serde_name,
     } = field;
 
     let mut field = model::ValidateField {
         ty,
         adapter: None,
         skip: None,
-        alias: None,
+        rename: None,
+        aliases: Vec::new(),
         // message: None,
         code: None,
         dive: None,
+        flatten: None,
         rule_set: model::RuleSet::empty(),
     };
 
@@ -247,6 +431,41 @@ fn check_field(field: model::Field, options: &model::Options) -> syn::Result<mod
         }
     }
 
+    // Conflicts are reported on whichever of the two rules was written last.
+    let skip_conflict = later_span(
+        &raw_rules,
+        |kind| matches!(kind, model::RawRuleKind::Skip),
+        |kind| {
+            !matches!(
+                kind,
+                model::RawRuleKind::Skip
+                    | model::RawRuleKind::Adapt(_)
+                    | model::RawRuleKind::Rename(_)
+                    | model::RawRuleKind::Alias(_)
+                    | model::RawRuleKind::Code(_)
+            )
+        },
+    );
+    let dive_conflict = later_span(
+        &raw_rules,
+        |kind| {
+            matches!(
+                kind,
+                model::RawRuleKind::Dive(_) | model::RawRuleKind::DiveDeref(_)
+            )
+        },
+        |kind| matches!(kind, model::RawRuleKind::Inner(_)),
+    );
+    let rename_span = raw_rules
+        .iter()
+        .find(|rule| {
+            matches!(
+                rule.kind,
+                model::RawRuleKind::Rename(_) | model::RawRuleKind::Alias(_)
+            )
+        })
+        .map(|rule| rule.span);
+
     field.rule_set = match check_rules(&mut field, raw_rules) {
         Ok(v) => v,
         Err(e) => {
@@ -255,19 +474,41 @@ fn check_field(field: model::Field, options: &model::Options) -> syn::Result<mod
         }
     };
 
+    if field.rename.is_none() {
+        field.rename = serde_name;
+    }
+
     if let Some(span) = field.skip {
         if !field.is_empty() {
             error.maybe_fold(syn::Error::new(
-                span,
+                skip_conflict.unwrap_or(span),
                 "`skip` may not be combined with other rules",
             ))
         }
     }
 
-    if let Some(span) = field.dive {
-        if field.rule_set.inner.is_some() {
+    if let Some(span) = field.flatten {
+        if field.dive.is_none() {
+            error.maybe_fold(syn::Error::new(span, "`flatten` requires `dive`"))
+        } else if !field.rule_set.is_empty() {
             error.maybe_fold(syn::Error::new(
                 span,
+                "`flatten` may only be combined with `dive`",
+            ))
+        }
+        // The errors of a flattened field are not nested under its key.
+        if let Some(rename_span) = rename_span {
+            error.maybe_fold(syn::Error::new(
+                rename_span,
+                "`flatten` may not be combined with `rename` or `alias`",
+            ))
+        }
+    }
+
+    if let Some((span, _)) = field.dive {
+        if field.rule_set.inner.is_some() {
+            error.maybe_fold(syn::Error::new(
+                dive_conflict.unwrap_or(span),
                 "`dive` may not be combined with `inner`",
             ))
         }
@@ -280,6 +521,18 @@ fn check_field(field: model::Field, options: &model::Options) -> syn::Result<mod
     Ok(field)
 }
 
+/// Finds the first rules matching `a` and `b`, and returns the span of
+/// whichever of the two comes later.
+fn later_span(
+    raw_rules: &[model::RawRule],
+    a: impl Fn(&model::RawRuleKind) -> bool,
+    b: impl Fn(&model::RawRuleKind) -> bool,
+) -> Option<Span> {
+    let a = raw_rules.iter().position(|rule| a(&rule.kind))?;
+    let b = raw_rules.iter().position(|rule| b(&rule.kind))?;
+    Some(raw_rules[a.max(b)].span)
+}
+
 fn check_rules(
     field: &mut model::ValidateField,
     raw_rules: Vec<model::RawRule>,
@@ -303,6 +556,8 @@ fn check_rule(
     rule_set: &mut model::RuleSet,
     is_inner: bool,
 ) -> syn::Result<()> {
+    let when = raw_rule.when;
+
     macro_rules! apply {
         ($name:ident = $value:expr, $span:expr) => {{
             if is_inner {
@@ -322,35 +577,110 @@ fn check_rule(
             }
         }};
 
-        ($rule:ident($($inner:expr)?), $span:expr) => {{
-            let rule = model::ValidateRule::$rule$(($inner))?;
-            let name = rule.name();
-            if !rule_set.rules.insert(rule) {
+        ($rule:ident(), $span:expr) => {
+            apply!(@insert model::ValidateRule::$rule, $span)
+        };
+
+        ($rule:ident($($inner:expr),+), $span:expr) => {
+            apply!(@insert model::ValidateRule::$rule($($inner),+), $span)
+        };
+
+        (@insert $rule:expr, $span:expr) => {{
+            let rule = $rule;
+            if rule_set
+                .rules
+                .iter()
+                .any(|(other, _)| matches!(other, model::Rule::Builtin(other) if **other == rule))
+            {
+                let name = rule.name();
                 return Err(syn::Error::new($span, format!("duplicate rule `{name}`")));
             }
+            rule_set.rules.push((model::Rule::Builtin(Box::new(rule)), when));
         }};
     }
 
     let span = raw_rule.span;
     use model::RawRuleKind::*;
+    if when.is_some()
+        && matches!(
+            raw_rule.kind,
+            Skip | Adapt(_)
+                | Rename(_)
+                | Alias(_)
+                | Code(_)
+                | Dive(_)
+                | DiveDeref(_)
+                | Flatten
+                | Inner(_)
+                | Tuple(_)
+        )
+    {
+        return Err(syn::Error::new(
+            span,
+            "`when` may only be used with validation rules, not with options, `dive`, `inner` or `tuple`",
+        ));
+    }
     match raw_rule.kind {
         Skip => apply!(skip = span, span),
         Adapt(path) => apply!(adapter = path, span),
-        Rename(alias) => apply!(alias = alias.value, span),
+        Rename(rename) => apply!(rename = rename.value, span),
+        Alias(alias) => {
+            if is_inner {
+                return Err(syn::Error::new(
+                    span,
+                    "rule `alias` may not be used in `inner`",
+                ));
+            }
+            if field.aliases.contains(&alias.value) {
+                return Err(syn::Error::new(span, "duplicate alias"));
+            }
+            field.aliases.push(alias.value)
+        }
         // Message(message) => apply!(message = message, span),
         Code(code) => apply!(code = code.value, span),
-        Dive => apply!(dive = span, span),
-        Custom(custom) => rule_set.custom_rules.push(custom),
+        Flatten => apply!(flatten = span, span),
+        Dive(_) | DiveDeref(_) => {
+            let dive = match raw_rule.kind {
+                Dive(v) => model::Dive {
+                    context: v.context,
+                    deref: false,
+                },
+                DiveDeref(v) => model::Dive {
+                    context: v.context,
+                    deref: true,
+                },
+                _ => unreachable!(),
+            };
+            if is_inner {
+                match rule_set.dive {
+                    Some(_) => return Err(syn::Error::new(span, "duplicate rule `dive`")),
+                    None => rule_set.dive = Some((span, dive)),
+                }
+            } else {
+                apply!(dive = (span, dive), span)
+            }
+        }
+        Custom(custom) => rule_set.rules.push((model::Rule::Custom(custom), when)),
+        CustomReport(custom) => rule_set
+            .rules
+            .push((model::Rule::CustomReport(custom), when)),
         Required => apply!(Required(), span),
-        Ascii => apply!(Ascii(), span),
+        Ascii(v) if v.printable => apply!(AsciiPrintable(), span),
+        Ascii(_) => apply!(Ascii(), span),
         Alphanumeric => apply!(Alphanumeric(), span),
+        EachChar(v) => apply!(EachChar(v), span),
+        Charset(v) => apply!(Charset(v), span),
         Email => apply!(Email(), span),
-        Url => apply!(Url(), span),
+        Url(v) => apply!(Url(v), span),
         Ip => apply!(Ip(), span),
         IpV4 => apply!(IpV4(), span),
         IpV6 => apply!(IpV6(), span),
+        Cidr => apply!(Cidr(), span),
+        CidrV4 => apply!(CidrV4(), span),
+        CidrV6 => apply!(CidrV6(), span),
         CreditCard => apply!(CreditCard(), span),
         PhoneNumber => apply!(PhoneNumber(), span),
+        SafePathComponent => apply!(SafePathComponent(), span),
         Length(v) => {
             let range = check_range_generic(v.range)?;
             match v.mode {
@@ -361,12 +691,25 @@ fn check_rule(
                 LengthMode::Utf16 => apply!(LengthUtf16(range), span),
             }
         }
+        ByteSize(v) => apply!(ByteSize(check_byte_size(v)?), span),
         Matches(path) => apply!(Matches(path), span),
-        Range(v) => apply!(Range(check_range_not_ord(v)?), span),
-        Contains(v) => apply!(Contains(v), span),
+        Range(v) => apply!(Range(check_range_not_ord(v.range)?, v.key), span),
+        Contains(syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Char(c),
+            ..
+        })) => apply!(Contains(model::ValidateContains::Char(c)), span),
+        Contains(v) => apply!(Contains(model::ValidateContains::Expr(v)), span),
+        ContainsAny(v) => apply!(ContainsAny(check_str_list(v)?), span),
+        ContainsAll(v) => apply!(ContainsAll(check_str_list(v)?), span),
+        ContainsItem(v) => apply!(ContainsItem(v), span),
+        PrefixAny(v) => apply!(PrefixAny(check_str_list(v)?), span),
+        SuffixAny(v) => apply!(SuffixAny(check_str_list(v)?), span),
         Prefix(v) => apply!(Prefix(v), span),
         Suffix(v) => apply!(Suffix(v), span),
-        Pattern(v) => apply!(Pattern(check_regex(v)?), span),
+        Glob(v) => apply!(Glob(v), span),
+        Pattern(v) => apply!(Pattern(check_regex(v.pattern)?, v.max_len), span),
+        NotPattern(v) => apply!(NotPattern(check_regex(v)?), span),
+        PatternCaptures(v) => apply!(PatternCaptures(check_regex(v.pattern)?, v.check), span),
         Inner(v) => {
             if rule_set.inner.is_none() {
                 rule_set.inner = Some(Box::new(model::RuleSet::empty()));
@@ -383,6 +726,39 @@ fn check_rule(
                 return Err(error);
             }
         }
+        Tuple(v) => {
+            let mut error = None;
+            for element in v.contents {
+                if rule_set
+                    .tuple
+                    .iter()
+                    .any(|(index, _)| *index == element.index)
+                {
+                    error.maybe_fold(syn::Error::new(
+                        element.span,
+                        format!("duplicate tuple element `{}`", element.index),
+                    ));
+                    continue;
+                }
+
+                let mut element_rule_set = model::RuleSet::empty();
+                for raw_rule in element.rules.contents {
+                    if let Err(e) = check_rule(field, raw_rule, &mut element_rule_set, true) {
+                        error.maybe_fold(e);
+                    }
+                }
+                rule_set.tuple.push((element.index, element_rule_set));
+            }
+            if let Some(error) = error {
+                return Err(error);
+            }
+        }
+        When(_) => {
+            return Err(syn::Error::new(
+                span,
+                "`when` may only be used at the top level of a field",
+            ))
+        }
     };
 
     Ok(())
@@ -518,6 +894,126 @@ fn check_range_not_ord<T>(range: model::Range<T>) -> syn::Result<model::Validate
     }
 }
 
+fn check_str_list(list: model::StrList) -> syn::Result<Vec<model::Str>> {
+    let mut error = None;
+
+    if list.values.is_empty() {
+        error.maybe_fold(syn::Error::new(list.span, "list must not be empty"));
+    }
+    for value in list.values.iter() {
+        if value.value.is_empty() {
+            error.maybe_fold(syn::Error::new(value.span, "string must not be empty"));
+        }
+    }
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    Ok(list.values)
+}
+
+fn check_byte_size(
+    range: model::Range<model::Str>,
+) -> syn::Result<model::ValidateRange<model::ByteSize>> {
+    let mut error = None;
+    let mut parse = |bound: Option<model::Str>| {
+        let repr = bound?;
+        match parse_byte_size(&repr.value) {
+            Ok(bytes) => Some(model::ByteSize { bytes, repr }),
+            Err(e) => {
+                error.maybe_fold(syn::Error::new(
+                    repr.span,
+                    format!("invalid byte size: {e}"),
+                ));
+                None
+            }
+        }
+    };
+    let min = parse(range.min);
+    let max = parse(range.max);
+    let equal = parse(range.equal);
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    check_range(model::Range {
+        span: range.span,
+        min,
+        max,
+        equal,
+    })
+}
+
+/// Parses a human-readable byte size, such as `10MB` or `1.5 GiB`, into a number of bytes.
+///
+/// This must accept the same inputs as `garde::rules::byte_size::parse_byte_size`.
+fn parse_byte_size(s: &str) -> Result<u64, &'static str> {
+    const UNITS: &[(&str, u64)] = &[
+        ("", 1),
+        ("b", 1),
+        ("kb", 1_000),
+        ("mb", 1_000_000),
+        ("gb", 1_000_000_000),
+        ("tb", 1_000_000_000_000),
+        ("pb", 1_000_000_000_000_000),
+        ("kib", 1 << 10),
+        ("mib", 1 << 20),
+        ("gib", 1 << 30),
+        ("tib", 1 << 40),
+        ("pib", 1 << 50),
+    ];
+    // Fractional digits beyond this precision are ignored.
+    const MAX_FRACTION_SCALE: u64 = 1_000_000_000_000;
+
+    let s = s.trim_matches(|c: char| c.is_ascii_whitespace());
+    if s.is_empty() {
+        return Err("value is empty");
+    }
+
+    let number_len = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (number, unit) = s.split_at(number_len);
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return Err("invalid number");
+    }
+    if fraction.contains('.') {
+        return Err("unknown unit");
+    }
+
+    let unit = unit.trim_start_matches(|c: char| c.is_ascii_whitespace());
+    let multiplier = UNITS
+        .iter()
+        .find(|(name, _)| unit.eq_ignore_ascii_case(name))
+        .map(|(_, multiplier)| *multiplier)
+        .ok_or("unknown unit")?;
+
+    let mut whole_value: u64 = 0;
+    for digit in whole.bytes() {
+        whole_value = whole_value
+            .checked_mul(10)
+            .and_then(|v| v.checked_add((digit - b'0') as u64))
+            .ok_or("value is too large")?;
+    }
+    let mut fraction_value: u64 = 0;
+    let mut scale: u64 = 1;
+    for digit in fraction.bytes() {
+        if scale < MAX_FRACTION_SCALE {
+            fraction_value = fraction_value * 10 + (digit - b'0') as u64;
+            scale *= 10;
+        }
+    }
+
+    let fraction_value = (fraction_value as u128 * multiplier as u128 / scale as u128) as u64;
+    whole_value
+        .checked_mul(multiplier)
+        .and_then(|v| v.checked_add(fraction_value))
+        .ok_or("value is too large")
+}
+
 fn check_regex(value: model::Pattern) -> syn::Result<model::ValidatePattern> {
     match value {
         model::Pattern::Lit(lit) => {
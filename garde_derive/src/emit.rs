@@ -16,12 +16,173 @@ impl ToTokens for model::Validate {
         let ident = &self.ident;
         let (context_ty, context_ident) = &self.context;
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let patterns = Patterns::default();
+        let catch_unwind = self.options.catch_unwind;
         let ty = Type {
             is_transparent: self.is_transparent,
             kind: &self.kind,
+            patterns: &patterns,
+            catch_unwind,
+        }
+        .to_token_stream();
+        let custom_fields = self
+            .custom_fields
+            .iter()
+            .map(|hook| Hook(hook, catch_unwind));
+
+        let validate_default = self.context_default.as_ref().map(|default| {
+            quote! {
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// Validates `self` using the default context from `#[garde(context(..., default = ...))]`.
+                    #[allow(dead_code)]
+                    pub fn validate_default(&self) -> ::core::result::Result<(), ::garde::Report> {
+                        let __garde_default_ctx: #context_ty = #default;
+                        ::garde::Validate::validate_with(self, &__garde_default_ctx)
+                    }
+                }
+            }
+        });
+
+        let span = cfg!(feature = "tracing").then(|| {
+            quote! {
+                let __garde_span = ::garde::external::tracing::trace_span!(
+                    "garde::validate",
+                    ty = stringify!(#ident),
+                )
+                .entered();
+            }
+        });
+        let skip_in_release = self.options.skip_in_release.then(|| {
+            quote! {
+                if ::core::cfg!(not(debug_assertions)) {
+                    return;
+                }
+            }
+        });
+
+        let validate_fields = match &self.kind {
+            model::ValidateKind::Struct(variant @ model::ValidateVariant::Struct(fields))
+                if self.options.validate_fields =>
+            {
+                let bindings = Bindings(variant);
+                let keys = fields.iter().map(|(ident, field)| match &field.rename {
+                    Some(rename) => rename.clone(),
+                    None => ident.to_string(),
+                });
+                let patterns = Patterns::default();
+                let fields = Struct {
+                    is_transparent: false,
+                    fields,
+                    patterns: &patterns,
+                    catch_unwind,
+                    only_listed: true,
+                }
+                .to_token_stream();
+                Some(quote! {
+                    impl #impl_generics #ident #ty_generics #where_clause {
+                        /// Validates only the fields whose names are in `fields`, and skips the rest.
+                        ///
+                        /// A field is matched by the name it is reported under, which is its `rename` if it has one.
+                        /// A name which doesn't match any field is reported as an error at that name.
+                        /// Container-level `validate` and `custom_fields` hooks are not run.
+                        #[allow(dead_code, clippy::needless_borrow)]
+                        pub fn validate_fields(
+                            &self,
+                            #context_ident: &#context_ty,
+                            __garde_fields: &[&str],
+                        ) -> ::core::result::Result<(), ::garde::Report> {
+                            let mut __garde_report = ::garde::Report::new();
+                            (|mut __garde_path: &mut dyn FnMut() -> ::garde::Path,
+                              __garde_report: &mut ::garde::error::Report| {
+                                #skip_in_release
+                                #span
+                                for __garde_field in __garde_fields {
+                                    if !<[&str]>::contains(&[#(#keys),*], __garde_field) {
+                                        __garde_report.append(
+                                            ::garde::Path::new(*__garde_field),
+                                            ::garde::Error::new("unknown field"),
+                                        );
+                                    }
+                                }
+
+                                let __garde_user_ctx = #context_ident;
+
+                                #patterns
+
+                                let Self #bindings = self;
+                                #fields
+                            })(&mut ::garde::Path::empty, &mut __garde_report);
+                            __garde_report.into_result()
+                        }
+                    }
+                })
+            }
+            _ => None,
         };
 
+        let body = quote! {
+            #skip_in_release
+            #span
+            let __garde_user_ctx = #context_ident;
+
+            #patterns
+
+            #ty
+
+            #(#custom_fields)*
+        };
+
+        if let Some(remote) = &self.remote {
+            // The remote type is foreign, so it can't implement `Validate`. A private
+            // trait is implemented for it instead, so that `self` refers to the value.
+            quote! {
+                const _: () = {
+                    trait __GardeRemote<__GardeContext: ?Sized> {
+                        fn __garde_validate_into(
+                            &self,
+                            ctx: &__GardeContext,
+                            __garde_path: &mut dyn FnMut() -> ::garde::Path,
+                            __garde_report: &mut ::garde::error::Report,
+                        );
+                    }
+
+                    impl #impl_generics __GardeRemote<#context_ty> for #remote #where_clause {
+                        #[allow(clippy::needless_borrow)]
+                        fn __garde_validate_into(
+                            &self,
+                            #context_ident: &#context_ty,
+                            mut __garde_path: &mut dyn FnMut() -> ::garde::Path,
+                            __garde_report: &mut ::garde::error::Report,
+                        ) {
+                            #body
+                        }
+                    }
+
+                    impl #impl_generics #ident #ty_generics #where_clause {
+                        /// Validates a value of the remote type using the rules declared on this type.
+                        #[allow(dead_code)]
+                        pub fn validate_remote(
+                            value: &#remote,
+                            ctx: &#context_ty,
+                        ) -> ::core::result::Result<(), ::garde::Report> {
+                            let mut report = ::garde::Report::new();
+                            __GardeRemote::__garde_validate_into(
+                                value,
+                                ctx,
+                                &mut ::garde::Path::empty,
+                                &mut report,
+                            );
+                            report.into_result()
+                        }
+                    }
+                };
+            }
+            .to_tokens(tokens);
+            return;
+        }
+
         quote! {
+            #[automatically_derived]
             impl #impl_generics ::garde::Validate for #ident #ty_generics #where_clause {
                 type Context = #context_ty ;
 
@@ -32,9 +193,93 @@ impl ToTokens for model::Validate {
                     mut __garde_path: &mut dyn FnMut() -> ::garde::Path,
                     __garde_report: &mut ::garde::error::Report,
                 ) {
-                    let __garde_user_ctx = &#context_ident;
+                    #body
+                }
+            }
+
+            #validate_default
+
+            #validate_fields
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// Literal patterns used within a single derive.
+///
+/// Each distinct pattern is compiled into one `static`, which is shared by
+/// every rule that uses it.
+#[derive(Default)]
+struct Patterns(RefCell<Vec<(String, TokenStream2, String)>>);
 
-                    #ty
+impl Patterns {
+    /// Returns the name of the `static` holding `pattern`, registering it if this is its first use.
+    #[cfg(feature = "regex")]
+    fn get(&self, rules_mod: &TokenStream2, pattern: &str) -> Ident {
+        let mut patterns = self.0.borrow_mut();
+        let key = rules_mod.to_string();
+        let index = match patterns
+            .iter()
+            .position(|(k, _, p)| *k == key && p == pattern)
+        {
+            Some(index) => index,
+            None => {
+                patterns.push((key, rules_mod.clone(), pattern.to_string()));
+                patterns.len() - 1
+            }
+        };
+        format_ident!("__GARDE_PATTERN_{index}")
+    }
+}
+
+impl ToTokens for Patterns {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        for (index, (_, rules_mod, pattern)) in self.0.borrow().iter().enumerate() {
+            let name = format_ident!("__GARDE_PATTERN_{index}");
+            quote! {
+                #[cfg(not(all(
+                    feature = "js-sys",
+                    target_arch = "wasm32",
+                    target_os = "unknown"
+                )))]
+                static #name: #rules_mod::pattern::regex::StaticPattern =
+                    #rules_mod::pattern::regex::init_pattern!(#pattern);
+
+                #[cfg(all(
+                    feature = "js-sys",
+                    target_arch = "wasm32",
+                    target_os = "unknown"
+                ))]
+                static #name: #rules_mod::pattern::regex_js_sys::StaticPattern =
+                    #rules_mod::pattern::regex_js_sys::init_pattern!(#pattern);
+            }
+            .to_tokens(tokens)
+        }
+    }
+}
+
+/// Calls a `#[garde(validate = ...)]` or `#[garde(custom_fields(...))]` hook with the whole value,
+/// and reports its errors relative to the current path.
+struct Hook<'a>(&'a syn::Expr, bool);
+
+impl<'a> ToTokens for Hook<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let hook = self.0;
+        let call = CatchUnwind {
+            enabled: self.1,
+            call: quote!((#hook)(self, __garde_user_ctx)),
+            returns_report: true,
+        };
+        quote! {
+            if let Err(__garde_fields_report) = #call {
+                let __garde_base_path = __garde_path();
+                for (__garde_error_path, __garde_error) in
+                    ::garde::error::Report::into_inner(__garde_fields_report)
+                {
+                    __garde_report.append(__garde_base_path.concat(&__garde_error_path), __garde_error);
+                }
+                if __garde_report.should_stop() {
+                    return;
                 }
             }
         }
@@ -42,20 +287,60 @@ impl ToTokens for model::Validate {
     }
 }
 
+/// Calls a user-provided function, converting a panic into an error if `enabled`.
+///
+/// The call must evaluate to `Result<(), garde::Error>`, or to `Result<(), garde::Report>` if `returns_report`,
+/// in which case the error of a panic is reported at the root of the report.
+struct CatchUnwind {
+    enabled: bool,
+    call: TokenStream2,
+    returns_report: bool,
+}
+
+impl ToTokens for CatchUnwind {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let call = &self.call;
+        match (self.enabled, self.returns_report) {
+            (false, _) => call.to_tokens(tokens),
+            (true, false) => quote! {
+                ::garde::util::__catch_unwind(|| #call).and_then(|__garde_result| __garde_result)
+            }
+            .to_tokens(tokens),
+            (true, true) => quote! {
+                match ::garde::util::__catch_unwind(|| #call) {
+                    Ok(__garde_result) => __garde_result,
+                    Err(__garde_error) => {
+                        let mut __garde_panic_report = ::garde::error::Report::new();
+                        __garde_panic_report.append_root(__garde_error);
+                        Err(__garde_panic_report)
+                    }
+                }
+            }
+            .to_tokens(tokens),
+        }
+    }
+}
+
 struct Type<'a> {
     is_transparent: bool,
     kind: &'a model::ValidateKind,
+    patterns: &'a Patterns,
+    catch_unwind: bool,
 }
 
 impl<'a> ToTokens for Type<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let is_transparent = self.is_transparent;
+        let patterns = self.patterns;
+        let catch_unwind = self.catch_unwind;
         match &self.kind {
             model::ValidateKind::Struct(variant) => {
                 let bindings = Bindings(variant);
                 let validation = Variant {
                     is_transparent,
                     variant,
+                    patterns,
+                    catch_unwind,
                 };
 
                 quote! {{
@@ -64,17 +349,25 @@ impl<'a> ToTokens for Type<'a> {
                 }}
             }
             model::ValidateKind::Enum(variants) => {
-                let variants = variants.iter().map(|(name, variant)| {
+                let variants = variants.iter().map(|(name, variant, hooks)| {
+                    let hooks = hooks.iter().map(|hook| Hook(hook, catch_unwind));
                     if let Some(variant) = variant {
                         let bindings = Bindings(variant);
                         let validation = Variant {
                             is_transparent,
                             variant,
+                            patterns,
+                            catch_unwind,
                         };
 
-                        quote!(Self::#name #bindings => #validation)
+                        quote! {
+                            Self::#name #bindings => {
+                                #validation
+                                #(#hooks)*
+                            }
+                        }
                     } else {
-                        quote!(Self::#name => {})
+                        quote!(Self::#name { .. } => { #(#hooks)* })
                     }
                 });
 
@@ -92,16 +385,23 @@ impl<'a> ToTokens for Type<'a> {
 struct Variant<'a> {
     is_transparent: bool,
     variant: &'a model::ValidateVariant,
+    patterns: &'a Patterns,
+    catch_unwind: bool,
 }
 
 impl<'a> ToTokens for Variant<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let is_transparent = self.is_transparent;
+        let patterns = self.patterns;
+        let catch_unwind = self.catch_unwind;
         match &self.variant {
             model::ValidateVariant::Struct(fields) => {
                 let fields = Struct {
                     is_transparent,
                     fields,
+                    patterns,
+                    catch_unwind,
+                    only_listed: false,
                 };
                 quote! {{#fields}}
             }
@@ -109,6 +409,8 @@ impl<'a> ToTokens for Variant<'a> {
                 let fields = Tuple {
                     is_transparent,
                     fields,
+                    patterns,
+                    catch_unwind,
                 };
                 quote! {{#fields}}
             }
@@ -120,22 +422,57 @@ impl<'a> ToTokens for Variant<'a> {
 struct Struct<'a> {
     is_transparent: bool,
     fields: &'a [(Ident, model::ValidateField)],
+    patterns: &'a Patterns,
+    catch_unwind: bool,
+    /// Only validate the fields whose key is in `__garde_fields`, for `validate_fields`.
+    only_listed: bool,
 }
 
 impl<'a> ToTokens for Struct<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         Fields::new(
-            self.fields
-                .iter()
-                .map(|(key, field)| (Binding::Ident(key), field, key.to_string())),
-            |key, value| match self.is_transparent {
-                true => quote! {{
+            self.patterns,
+            self.catch_unwind,
+            self.fields.iter().map(|(ident, field)| {
+                let key = match &field.rename {
+                    Some(rename) => quote!(#rename),
+                    None => ident.to_string().to_token_stream(),
+                };
+                (
+                    Binding::Ident(ident),
+                    field,
+                    (key, &field.aliases[..], field.flatten.is_some()),
+                )
+            }),
+            |(key, aliases, flatten), value| match (self.is_transparent, self.only_listed) {
+                (true, _) => quote! {{
                     #value
                 }},
-                false => quote! {{
-                    let mut __garde_path = ::garde::util::nested_path!(__garde_path, #key);
+                // The errors of a flattened field are reported at the path of the struct itself.
+                (false, false) if flatten => quote! {{
                     #value
                 }},
+                (false, true) if flatten => quote! {
+                    if __garde_fields.contains(&#key) {
+                        #value
+                    }
+                },
+                (false, false) => FieldPath {
+                    key,
+                    aliases,
+                    value,
+                }
+                .to_token_stream(),
+                (false, true) => {
+                    let field = FieldPath {
+                        key: key.clone(),
+                        aliases,
+                        value,
+                    };
+                    quote! {
+                        if __garde_fields.contains(&#key) #field
+                    }
+                }
             },
         )
         .to_tokens(tokens)
@@ -145,32 +482,97 @@ impl<'a> ToTokens for Struct<'a> {
 struct Tuple<'a> {
     is_transparent: bool,
     fields: &'a [model::ValidateField],
+    patterns: &'a Patterns,
+    catch_unwind: bool,
 }
 
 impl<'a> ToTokens for Tuple<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         Fields::new(
-            self.fields
-                .iter()
-                .enumerate()
-                .map(|(index, field)| (Binding::Index(index), field, index)),
-            |index, value| match self.is_transparent {
+            self.patterns,
+            self.catch_unwind,
+            self.fields.iter().enumerate().map(|(index, field)| {
+                let key = match &field.rename {
+                    Some(rename) => quote!(#rename),
+                    None => index.to_token_stream(),
+                };
+                (Binding::Index(index), field, (key, &field.aliases[..]))
+            }),
+            |(key, aliases), value| match self.is_transparent {
                 true => quote! {{
                     #value
                 }},
-                false => quote! {{
-                    let mut __garde_path = ::garde::util::nested_path!(__garde_path, #index);
-                    #value
-                }},
+                false => FieldPath {
+                    key,
+                    aliases,
+                    value,
+                }
+                .to_token_stream(),
             },
         )
         .to_tokens(tokens)
     }
 }
 
+/// Validates a field at the path `key`.
+///
+/// If the field has any aliases, its errors are collected separately,
+/// and then reported under `key` followed by each alias.
+struct FieldPath<'a> {
+    key: TokenStream2,
+    aliases: &'a [String],
+    value: TokenStream2,
+}
+
+impl<'a> ToTokens for FieldPath<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let FieldPath {
+            key,
+            aliases,
+            value,
+        } = self;
+
+        if aliases.is_empty() {
+            quote! {{
+                let mut __garde_path = ::garde::util::nested_path!(__garde_path, #key);
+                #value
+            }}
+            .to_tokens(tokens);
+            return;
+        }
+
+        let keys = std::iter::once(key.clone()).chain(aliases.iter().map(|alias| quote!(#alias)));
+        quote! {{
+            let mut __garde_field_report = ::garde::error::Report::new();
+            (|__garde_report: &mut ::garde::error::Report| {
+                let mut __garde_path = ::garde::Path::empty;
+                #value
+            })(&mut __garde_field_report);
+            if !__garde_field_report.is_empty() {
+                let __garde_base_path = __garde_path();
+                #({
+                    let __garde_key_path = __garde_base_path.join(#keys);
+                    for (__garde_error_path, __garde_error) in __garde_field_report.iter() {
+                        __garde_report.append(
+                            __garde_key_path.concat(__garde_error_path),
+                            __garde_error.clone(),
+                        );
+                    }
+                })*
+                if __garde_report.should_stop() {
+                    return;
+                }
+            }
+        }}
+        .to_tokens(tokens)
+    }
+}
+
 struct Inner<'a> {
     rules_mod: &'a TokenStream2,
     rule_set: &'a model::RuleSet,
+    patterns: &'a Patterns,
+    catch_unwind: bool,
 }
 
 impl<'a> ToTokens for Inner<'a> {
@@ -178,32 +580,36 @@ impl<'a> ToTokens for Inner<'a> {
         let Inner {
             rules_mod,
             rule_set,
-        } = self;
+            patterns,
+            catch_unwind,
+        } = *self;
 
+        let mut inner = rule_set.inner.as_deref().map(|rule_set| {
+            Inner {
+                rules_mod,
+                rule_set,
+                patterns,
+                catch_unwind,
+            }
+            .to_token_stream()
+        });
         let outer = match rule_set.has_top_level_rules() {
             true => {
                 let rules = Rules {
                     rules_mod,
                     rule_set,
+                    patterns,
+                    catch_unwind,
+                    tail: inner.take(),
                 };
                 Some(quote! {#rules})
             }
             false => None,
         };
-        let inner = rule_set.inner.as_deref().map(|rule_set| Inner {
-            rules_mod,
-            rule_set,
-        });
 
         let value = match (outer, inner) {
-            (Some(outer), Some(inner)) => quote! {
-                #outer
-                #inner
-            },
-            (None, Some(inner)) => quote! {
-                #inner
-            },
-            (Some(outer), None) => outer,
+            (Some(outer), _) => outer,
+            (None, Some(inner)) => inner,
             (None, None) => return,
         };
 
@@ -215,6 +621,50 @@ impl<'a> ToTokens for Inner<'a> {
                     #value
                 }
             );
+            if __garde_report.should_stop() {
+                return;
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// Calls `validate_into` on the current binding, using `context` if present, or the user context otherwise.
+struct Dive<'a>(&'a model::Dive);
+
+impl<'a> ToTokens for Dive<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let context = match &self.0.context {
+            model::DiveContext::User => quote!(__garde_user_ctx),
+            model::DiveContext::Expr(expr) => quote_spanned!(expr.span() => &(#expr)),
+            model::DiveContext::AsRef => quote!(::core::convert::AsRef::as_ref(__garde_user_ctx)),
+        };
+        let validate = if self.0.deref {
+            // Method call syntax auto-derefs through any references and smart pointers
+            // until it reaches a type which implements `Validate`.
+            quote! {{
+                use ::garde::validate::Validate as _;
+                (&**__garde_binding).validate_into(
+                    #context,
+                    &mut __garde_path,
+                    __garde_report,
+                );
+            }}
+        } else {
+            quote! {
+                ::garde::validate::Validate::validate_into(
+                    &*__garde_binding,
+                    #context,
+                    &mut __garde_path,
+                    __garde_report,
+                );
+            }
+        };
+        quote! {
+            #validate
+            if __garde_report.should_stop() {
+                return;
+            }
         }
         .to_tokens(tokens)
     }
@@ -223,6 +673,11 @@ impl<'a> ToTokens for Inner<'a> {
 struct Rules<'a> {
     rules_mod: &'a TokenStream2,
     rule_set: &'a model::RuleSet,
+    patterns: &'a Patterns,
+    /// Wrap calls to `custom` and `custom_report` functions in `catch_unwind`.
+    catch_unwind: bool,
+    /// Emitted after all other rules, and skipped along with them if a `fatal` rule fails.
+    tail: Option<TokenStream2>,
 }
 
 #[derive(Clone, Copy)]
@@ -242,116 +697,417 @@ impl<'a> ToTokens for Binding<'a> {
 
 impl<'a> ToTokens for Rules<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        let Rules {
-            rules_mod,
-            rule_set,
-        } = self;
+        // Rules run in the order they were written. Everything after a `fatal`
+        // custom rule is nested in its `else` branch, so it only runs if the rule succeeds.
+        let mut rest = TokenStream2::new();
+        self.rest_to_tokens(&mut rest);
 
-        for custom_rule in rule_set.custom_rules.iter() {
-            quote! {
-                if let Err(__garde_error) = (#custom_rule)(&*__garde_binding, &__garde_user_ctx) {
-                    __garde_report.append(__garde_path(), __garde_error);
+        for (rule, when) in self.rule_set.rules.iter().rev() {
+            rest = match rule {
+                model::Rule::Custom(custom_rule) => self.custom(custom_rule, when, rest),
+                model::Rule::CustomReport(custom_rule) => {
+                    let check = Guarded(when, self.custom_report(custom_rule));
+                    quote! {
+                        #check
+                        #rest
+                    }
+                }
+                model::Rule::Builtin(rule) => {
+                    let check = Guarded(when, self.builtin(rule));
+                    quote! {
+                        #check
+                        #rest
+                    }
+                }
+            };
+        }
+
+        rest.to_tokens(tokens)
+    }
+}
+
+impl<'a> Rules<'a> {
+    fn custom(
+        &self,
+        custom_rule: &model::CustomRule,
+        when: &Option<syn::Expr>,
+        rest: TokenStream2,
+    ) -> TokenStream2 {
+        let model::CustomRule { expr, fatal } = custom_rule;
+        let func = match expr {
+            syn::Expr::Path(_) => quote_spanned!(expr.span() => ::garde::util::__custom_fn(#expr)),
+            _ => quote!(#expr),
+        };
+        let call = Traced {
+            rule: "custom",
+            validator: Some(expr),
+            call: CatchUnwind {
+                enabled: self.catch_unwind,
+                call: quote!((#func)(&*__garde_binding, __garde_user_ctx)),
+                returns_report: false,
+            }
+            .to_token_stream(),
+        };
+        let check = quote! {
+            if let Err(__garde_error) = #call {
+                __garde_report.append(__garde_path(), __garde_error);
+                if __garde_report.should_stop() {
+                    return;
+                }
+            }
+        };
+        match (fatal, when) {
+            (true, None) => quote! {
+                #check else {
+                    #rest
+                }
+            },
+            // The rest is only emitted once, so whether the guarded
+            // rule failed is tracked in a flag instead.
+            (true, Some(when)) => {
+                let when = Guard(when);
+                quote! {
+                    #when
+                    let __garde_fatal = __garde_when
+                        && match #call {
+                            Ok(_) => false,
+                            Err(__garde_error) => {
+                                __garde_report.append(__garde_path(), __garde_error);
+                                if __garde_report.should_stop() {
+                                    return;
+                                }
+                                true
+                            }
+                        };
+                    if !__garde_fatal {
+                        #rest
+                    }
+                }
+            }
+            (false, when) => {
+                let check = Guarded(when, check);
+                quote! {
+                    #check
+                    #rest
                 }
             }
-            .to_tokens(tokens);
         }
+    }
 
-        for rule in rule_set.rules.iter() {
-            let name = TokenStream2::from_str(rule.name()).unwrap();
-            use model::ValidateRule::*;
-            let args = match rule {
-                Ascii | Alphanumeric | Email | Url | CreditCard | PhoneNumber | Required => {
-                    quote!(())
+    fn custom_report(&self, custom_rule: &syn::Expr) -> TokenStream2 {
+        let call = Traced {
+            rule: "custom_report",
+            validator: Some(custom_rule),
+            call: CatchUnwind {
+                enabled: self.catch_unwind,
+                call: quote!((#custom_rule)(&*__garde_binding, __garde_user_ctx)),
+                returns_report: true,
+            }
+            .to_token_stream(),
+        };
+        quote! {
+            if let Err(__garde_custom_report) = #call {
+                let __garde_base_path = __garde_path();
+                for (__garde_error_path, __garde_error) in
+                    ::garde::error::Report::into_inner(__garde_custom_report)
+                {
+                    __garde_report.append(__garde_base_path.concat(&__garde_error_path), __garde_error);
                 }
-                Ip => {
-                    quote!((#rules_mod::ip::IpKind::Any,))
+                if __garde_report.should_stop() {
+                    return;
                 }
-                IpV4 => {
-                    quote!((#rules_mod::ip::IpKind::V4,))
+            }
+        }
+    }
+
+    fn builtin(&self, rule: &model::ValidateRule) -> TokenStream2 {
+        let rules_mod = self.rules_mod;
+
+        let name = TokenStream2::from_str(rule.name()).unwrap();
+        use model::ValidateRule::*;
+        let args = match rule {
+            Ascii | AsciiPrintable | Alphanumeric | Email | CreditCard | PhoneNumber
+            | SafePathComponent | Required => {
+                quote!(())
+            }
+            Url(model::UrlFlags {
+                require_host: false,
+                https: false,
+            }) => {
+                quote!(())
+            }
+            Url(model::UrlFlags {
+                require_host,
+                https,
+            }) => {
+                quote!((#require_host, #https))
+            }
+            Ip | Cidr => {
+                quote!((#rules_mod::ip::IpKind::Any,))
+            }
+            IpV4 | CidrV4 => {
+                quote!((#rules_mod::ip::IpKind::V4,))
+            }
+            IpV6 | CidrV6 => {
+                quote!((#rules_mod::ip::IpKind::V6,))
+            }
+            LengthSimple(range)
+            | LengthBytes(range)
+            | LengthChars(range)
+            | LengthGraphemes(range)
+            | LengthUtf16(range) => match range {
+                model::ValidateRange::GreaterThan(min) => {
+                    quote!((#min, usize::MAX))
                 }
-                IpV6 => {
-                    quote!((#rules_mod::ip::IpKind::V6,))
+                model::ValidateRange::LowerThan(max) => {
+                    quote!((0usize, #max))
                 }
-                LengthSimple(range)
-                | LengthBytes(range)
-                | LengthChars(range)
-                | LengthGraphemes(range)
-                | LengthUtf16(range) => match range {
-                    model::ValidateRange::GreaterThan(min) => {
-                        quote!((#min, usize::MAX))
-                    }
-                    model::ValidateRange::LowerThan(max) => {
-                        quote!((0usize, #max))
+                model::ValidateRange::Between(min, max) => {
+                    quote!((#min, #max))
+                }
+                model::ValidateRange::Equal(equal) => {
+                    quote!((#equal, #equal))
+                }
+            },
+            ByteSize(range) => {
+                let (min, max) = match range {
+                    model::ValidateRange::GreaterThan(min) => (Some(min), None),
+                    model::ValidateRange::LowerThan(max) => (None, Some(max)),
+                    model::ValidateRange::Between(min, max) => (Some(min), Some(max)),
+                    model::ValidateRange::Equal(equal) => (Some(equal), Some(equal)),
+                };
+                let size = |size: Option<&model::ByteSize>| match size {
+                    Some(model::ByteSize { bytes, repr }) => {
+                        quote!(Some(#rules_mod::byte_size::Size::parsed(#bytes, #repr)))
                     }
-                    model::ValidateRange::Between(min, max) => {
-                        quote!((#min, #max))
+                    None => quote!(None),
+                };
+                let (min, max) = (size(min), size(max));
+                quote!((#min, #max))
+            }
+            Matches(path) => {
+                quote!((stringify!(#path), &self.#path))
+            }
+            Range(range, _) => match range {
+                model::ValidateRange::GreaterThan(min) => quote!((Some(#min), None)),
+                model::ValidateRange::LowerThan(max) => quote!((None, Some(#max))),
+                model::ValidateRange::Between(min, max) => quote!((Some(#min), Some(#max))),
+                model::ValidateRange::Equal(equal) => quote!((#equal,)),
+            },
+            Contains(model::ValidateContains::Expr(expr))
+            | ContainsItem(expr)
+            | Prefix(expr)
+            | Suffix(expr)
+            | Glob(expr)
+            | EachChar(expr) => {
+                quote_spanned!(expr.span() => (&#expr,))
+            }
+            Contains(model::ValidateContains::Char(c)) => quote_spanned!(c.span() => (#c,)),
+            Charset(charset) => match charset {
+                model::Charset::Ascii => quote!((#rules_mod::charset::Charset::Ascii,)),
+                model::Charset::Latin1 => quote!((#rules_mod::charset::Charset::Latin1,)),
+                model::Charset::Max(max) => {
+                    quote_spanned!(max.span() => (#rules_mod::charset::Charset::Max(#max),))
+                }
+            },
+            ContainsAny(list) | ContainsAll(list) | PrefixAny(list) | SuffixAny(list) => {
+                quote!((&[#(#list),*],))
+            }
+            Pattern(pat, max_len) => {
+                let max_len = match max_len {
+                    Some(max_len) => quote_spanned!(max_len.span() => Some(#max_len)),
+                    None => quote!(None),
+                };
+                match pat {
+                    model::ValidatePattern::Expr(expr) => {
+                        quote_spanned!(expr.span() => (&#expr, #max_len))
                     }
-                    model::ValidateRange::Equal(equal) => {
-                        quote!((#equal, #equal))
+                    #[cfg(feature = "regex")]
+                    model::ValidatePattern::Lit(s) => {
+                        let pattern = self.patterns.get(rules_mod, s);
+                        quote!((&#pattern, #max_len))
                     }
-                },
-                Matches(path) => {
-                    quote!((stringify!(#path), &self.#path))
                 }
-                Range(range) => match range {
-                    model::ValidateRange::GreaterThan(min) => quote!((Some(#min), None)),
-                    model::ValidateRange::LowerThan(max) => quote!((None, Some(#max))),
-                    model::ValidateRange::Between(min, max) => quote!((Some(#min), Some(#max))),
-                    model::ValidateRange::Equal(equal) => quote!((Some(#equal), Some(#equal))),
-                },
-                Contains(expr) | Prefix(expr) | Suffix(expr) => {
-                    quote_spanned!(expr.span() => (&#expr,))
+            }
+            NotPattern(pat) => match pat {
+                model::ValidatePattern::Expr(expr) => quote_spanned!(expr.span() => (&#expr,)),
+                #[cfg(feature = "regex")]
+                model::ValidatePattern::Lit(s) => {
+                    let pattern = self.patterns.get(rules_mod, s);
+                    quote!((&#pattern,))
                 }
-                Pattern(pat) => match pat {
-                    model::ValidatePattern::Expr(expr) => quote_spanned!(expr.span() => (&#expr,)),
+            },
+            PatternCaptures(pat, check) => {
+                let pattern = match pat {
+                    model::ValidatePattern::Expr(expr) => quote_spanned!(expr.span() => &#expr),
                     #[cfg(feature = "regex")]
-                    model::ValidatePattern::Lit(s) => quote!({
-                        #[cfg(not(all(
-                            feature = "js-sys",
-                            target_arch = "wasm32",
-                            target_os = "unknown"
-                        )))]
-                        static PATTERN: #rules_mod::pattern::regex::StaticPattern =
-                            #rules_mod::pattern::regex::init_pattern!(#s);
-
-                        #[cfg(all(
-                            feature = "js-sys",
-                            target_arch = "wasm32",
-                            target_os = "unknown"
-                        ))]
-                        static PATTERN: #rules_mod::pattern::regex_js_sys::StaticPattern =
-                            #rules_mod::pattern::regex_js_sys::init_pattern!(#s);
-
-                        (&PATTERN,)
-                    }),
-                },
-            };
+                    model::ValidatePattern::Lit(s) => {
+                        let pattern = self.patterns.get(rules_mod, s);
+                        quote!(&#pattern)
+                    }
+                };
+                quote_spanned!(check.span() => (#pattern, &#check, __garde_user_ctx))
+            }
+        };
 
-            quote! {
-                if let Err(__garde_error) = (#rules_mod::#name::apply)(&*__garde_binding, #args) {
-                    __garde_report.append(__garde_path(), __garde_error);
+        let value = match rule {
+            Range(_, Some(key)) => quote_spanned!(key.span() => &(#key)(&*__garde_binding)),
+            _ => quote!(&*__garde_binding),
+        };
+
+        let entrypoint = match rule {
+            Range(model::ValidateRange::Equal(_), _) => quote!(equal),
+            Url(flags) if flags.require_host || flags.https => quote!(apply_with),
+            Contains(model::ValidateContains::Char(_)) => quote!(single_char::apply),
+            _ => quote!(apply),
+        };
+
+        let call = Traced {
+            rule: rule.name(),
+            validator: None,
+            call: quote!((#rules_mod::#name::#entrypoint)(#value, #args)),
+        };
+        quote! {
+            if let Err(__garde_error) = #call {
+                __garde_report.append(__garde_path(), __garde_error);
+                if __garde_report.should_stop() {
+                    return;
                 }
             }
-            .to_tokens(tokens)
         }
     }
+
+    fn rest_to_tokens(&self, tokens: &mut TokenStream2) {
+        let Rules {
+            rules_mod,
+            rule_set,
+            patterns,
+            catch_unwind,
+            tail,
+        } = self;
+
+        if let Some((_, dive)) = &rule_set.dive {
+            Dive(dive).to_tokens(tokens);
+        }
+
+        for (index, rule_set) in rule_set.tuple.iter() {
+            let element = syn::Index::from(*index);
+            let mut inner = rule_set.inner.as_deref().map(|rule_set| {
+                Inner {
+                    rules_mod,
+                    rule_set,
+                    patterns,
+                    catch_unwind: *catch_unwind,
+                }
+                .to_token_stream()
+            });
+            let rules = rule_set.has_top_level_rules().then(|| Rules {
+                rules_mod,
+                rule_set,
+                patterns,
+                catch_unwind: *catch_unwind,
+                tail: inner.take(),
+            });
+            quote! {{
+                let __garde_binding = &__garde_binding.#element;
+                let mut __garde_path = ::garde::util::nested_path!(__garde_path, #index);
+                #rules
+                #inner
+            }}
+            .to_tokens(tokens);
+        }
+
+        tail.to_tokens(tokens);
+    }
+}
+
+/// A call to a rule, which is wrapped in a `tracing` span with the `tracing` feature.
+///
+/// The span's fields are only evaluated if it is enabled, so the path is not built otherwise.
+struct Traced<'a> {
+    rule: &'a str,
+    validator: Option<&'a syn::Expr>,
+    call: TokenStream2,
+}
+
+impl<'a> ToTokens for Traced<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let Traced {
+            rule,
+            validator,
+            call,
+        } = self;
+        if !cfg!(feature = "tracing") {
+            return call.to_tokens(tokens);
+        }
+
+        let validator = validator.map(|expr| quote!(validator = stringify!(#expr),));
+        quote! {{
+            let __garde_span = ::garde::external::tracing::trace_span!(
+                "garde::rule",
+                rule = #rule,
+                #validator
+                path = %__garde_path(),
+            )
+            .entered();
+            #call
+        }}
+        .to_tokens(tokens)
+    }
+}
+
+/// Evaluates a `when` guard into `__garde_when`.
+struct Guard<'a>(&'a syn::Expr);
+
+impl<'a> ToTokens for Guard<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let expr = self.0;
+        quote_spanned! {expr.span()=>
+            let __garde_when: bool = #expr;
+        }
+        .to_tokens(tokens)
+    }
 }
 
-struct Fields<I, F>(RefCell<Option<I>>, F);
+/// A check which only runs if its `when` guard is true.
+struct Guarded<'a>(&'a Option<syn::Expr>, TokenStream2);
 
-impl<I, F> Fields<I, F> {
-    fn new(iter: I, f: F) -> Self {
-        Self(RefCell::new(Some(iter)), f)
+impl<'a> ToTokens for Guarded<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let Guarded(when, check) = self;
+        match when {
+            Some(when) => {
+                let when = Guard(when);
+                quote! {{
+                    #when
+                    if __garde_when {
+                        #check
+                    }
+                }}
+                .to_tokens(tokens)
+            }
+            None => check.to_tokens(tokens),
+        }
     }
 }
 
-impl<'a, I, F, Extra> ToTokens for Fields<I, F>
+struct Fields<'a, I, F>(&'a Patterns, bool, RefCell<Option<I>>, F);
+
+impl<'a, I, F> Fields<'a, I, F> {
+    fn new(patterns: &'a Patterns, catch_unwind: bool, iter: I, f: F) -> Self {
+        Self(patterns, catch_unwind, RefCell::new(Some(iter)), f)
+    }
+}
+
+impl<'a, I, F, Extra> ToTokens for Fields<'a, I, F>
 where
     I: Iterator<Item = (Binding<'a>, &'a model::ValidateField, Extra)> + 'a,
     F: Fn(Extra, TokenStream2) -> TokenStream2,
 {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        let fields = match self.0.borrow_mut().take() {
+        let patterns = self.0;
+        let catch_unwind = self.1;
+        let fields = match self.2.borrow_mut().take() {
             Some(v) => v,
             None => return,
         };
@@ -367,27 +1123,14 @@ where
                 Some(_) => &field_adapter,
                 None => &default_rules_mod,
             };
-            let rules = Rules {
-                rules_mod,
-                rule_set: &field.rule_set,
-            };
-            let outer = match field.has_top_level_rules() {
-                true => Some(quote! {{#rules}}),
-                false => None,
-            };
-            let inner = match (&field.dive, &field.rule_set.inner) {
-                (Some(..), None) => Some(quote! {
-                    ::garde::validate::Validate::validate_into(
-                        &*__garde_binding,
-                        __garde_user_ctx,
-                        &mut __garde_path,
-                        __garde_report,
-                    );
-                }),
+            let mut inner = match (&field.dive, &field.rule_set.inner) {
+                (Some((_, dive)), None) => Some(Dive(dive).to_token_stream()),
                 (None, Some(inner)) => Some(
                     Inner {
                         rules_mod,
                         rule_set: inner,
+                        patterns,
+                        catch_unwind,
                     }
                     .to_token_stream(),
                 ),
@@ -395,25 +1138,31 @@ where
                 // TODO: encode this via the type system instead?
                 _ => unreachable!("`dive` and `inner` are mutually exclusive"),
             };
+            // `dive` and `inner` run after all other rules, so that a `fatal` rule may skip them.
+            let outer = field.has_top_level_rules().then(|| {
+                let rules = Rules {
+                    rules_mod,
+                    rule_set: &field.rule_set,
+                    patterns,
+                    catch_unwind,
+                    tail: inner.take(),
+                };
+                quote! {{#rules}}
+            });
 
             let value = match (outer, inner) {
-                (Some(outer), Some(inner)) => quote! {
+                (Some(outer), _) => quote! {
                     let __garde_binding = &*#binding;
-                    #inner
                     #outer
                 },
                 (None, Some(inner)) => quote! {
                     let __garde_binding = &*#binding;
                     #inner
                 },
-                (Some(outer), None) => quote! {
-                    let __garde_binding = &*#binding;
-                    #outer
-                },
                 (None, None) => unreachable!("field should already be skipped"),
             };
 
-            let add = &self.1;
+            let add = &self.3;
 
             add(extra, value).to_tokens(tokens)
         }
@@ -440,19 +1189,17 @@ impl<'a> ToTokens for Bindings<'a> {
                 quote!( { #(#names,)* #rest } )
             }
             model::ValidateVariant::Tuple(fields) => {
+                // Tuple fields are bound by position, so skipped fields are
+                // matched by `_` to keep the following fields in place.
                 let indices = fields
                     .iter()
                     .enumerate()
-                    .filter(|(_, field)| field.skip.is_none())
-                    .map(|(i, _)| IndexBinding(i))
-                    .collect::<Vec<_>>();
-                let rest = if indices.len() != fields.len() {
-                    Some(quote!(..))
-                } else {
-                    None
-                };
+                    .map(|(i, field)| match field.skip {
+                        Some(_) => quote!(_),
+                        None => IndexBinding(i).to_token_stream(),
+                    });
 
-                quote!( ( #(#indices,)* #rest ) )
+                quote!( ( #(#indices,)* ) )
             }
         }
         .to_tokens(tokens)
@@ -1,6 +1,7 @@
 mod check;
 mod emit;
 mod model;
+mod serde_attr;
 mod syntax;
 mod util;
 
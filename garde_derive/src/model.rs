@@ -1,7 +1,7 @@
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::BTreeMap;
 
 use proc_macro2::{Ident, Span};
-use syn::{Expr, Generics, Path, Type};
+use syn::{Expr, Generics, Path, Type, TypeParamBound};
 
 pub struct Input {
     pub ident: Ident,
@@ -12,9 +12,23 @@ pub struct Input {
 
 #[repr(u8)]
 pub enum Attr {
-    Context(Box<Type>, Ident),
+    Context(Box<RawContext>),
+    CustomFields(Box<Expr>),
+    Validate(Box<Expr>),
     AllowUnvalidated,
     Transparent,
+    Remote(Box<Type>),
+    SkipInRelease,
+    CatchUnwind,
+    ValidateFields,
+}
+
+#[derive(Clone)]
+pub struct RawContext {
+    pub ty: Type,
+    pub bounds: Vec<TypeParamBound>,
+    pub ident: Ident,
+    pub default: Option<Expr>,
 }
 
 impl Attr {
@@ -29,15 +43,22 @@ impl Attr {
     pub fn name(&self) -> &'static str {
         match self {
             Attr::Context(..) => "context",
+            Attr::CustomFields(..) => "custom_fields",
+            Attr::Validate(..) => "validate",
             Attr::AllowUnvalidated => "allow_unvalidated",
             Attr::Transparent => "transparent",
+            Attr::Remote(..) => "remote",
+            Attr::SkipInRelease => "skip_in_release",
+            Attr::CatchUnwind => "catch_unwind",
+            Attr::ValidateFields => "validate_fields",
         }
     }
 }
 
 pub enum InputKind {
     Struct(Variant),
-    Enum(Vec<(Ident, Option<Variant>)>),
+    /// Each variant is stored with the hooks from its `#[garde(validate = ...)]` attributes.
+    Enum(Vec<(Ident, Option<Variant>, Vec<Expr>)>),
 }
 
 impl InputKind {
@@ -60,6 +81,8 @@ impl Variant {
 pub struct Field {
     pub ty: Type,
     pub rules: Vec<RawRule>,
+    /// The field's name according to its `#[serde]` attributes, used as its key unless it has a `rename` rule.
+    pub serde_name: Option<String>,
 }
 
 // pub enum Message {
@@ -70,34 +93,123 @@ pub struct Field {
 pub struct RawRule {
     pub span: Span,
     pub kind: RawRuleKind,
+    /// The `when` guard written in the same `#[garde(...)]` attribute, if any.
+    pub when: Option<Expr>,
 }
 
 pub enum RawRuleKind {
     Skip,
     Adapt(Path),
     Rename(Str),
+    Alias(Str),
     // Message(Message),
     Code(Str),
-    Dive,
+    Dive(RawDive),
+    DiveDeref(RawDive),
+    Flatten,
     Required,
-    Ascii,
+    Ascii(RawAscii),
     Alphanumeric,
+    EachChar(Expr),
+    Charset(Charset),
     Email,
-    Url,
+    Url(UrlFlags),
     Ip,
     IpV4,
     IpV6,
+    Cidr,
+    CidrV4,
+    CidrV6,
     CreditCard,
     PhoneNumber,
+    SafePathComponent,
     Length(RawLength),
+    ByteSize(Range<Str>),
     Matches(Path),
-    Range(Range<Expr>),
+    Range(RawRange),
     Contains(Expr),
+    ContainsAny(StrList),
+    ContainsAll(StrList),
+    ContainsItem(Expr),
+    PrefixAny(StrList),
+    SuffixAny(StrList),
     Prefix(Expr),
     Suffix(Expr),
-    Pattern(Pattern),
-    Custom(Expr),
+    Glob(Expr),
+    Pattern(RawPattern),
+    NotPattern(Pattern),
+    PatternCaptures(RawPatternCaptures),
+    Custom(CustomRule),
+    CustomReport(Expr),
     Inner(List<RawRule>),
+    Tuple(List<RawTupleElement>),
+    When(Expr),
+}
+
+pub struct CustomRule {
+    pub expr: Expr,
+    /// If the rule fails, no further rules are run on the value.
+    pub fatal: bool,
+}
+
+pub struct RawAscii {
+    pub printable: bool,
+}
+
+/// A `byte_size` bound, parsed from a string literal such as `"10MB"`.
+pub struct ByteSize {
+    pub bytes: u64,
+    pub repr: Str,
+}
+
+impl PartialEq for ByteSize {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl PartialOrd for ByteSize {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.bytes.partial_cmp(&other.bytes)
+    }
+}
+
+pub enum Charset {
+    Ascii,
+    Latin1,
+    Max(Expr),
+}
+
+pub struct UrlFlags {
+    pub require_host: bool,
+    pub https: bool,
+}
+
+pub struct RawTupleElement {
+    pub span: Span,
+    pub index: usize,
+    pub rules: List<RawRule>,
+}
+
+pub struct RawDive {
+    pub context: DiveContext,
+}
+
+/// A `dive` or `dive_deref` rule.
+pub struct Dive {
+    pub context: DiveContext,
+    /// `dive_deref`, which validates the `Deref` target of the value.
+    pub deref: bool,
+}
+
+/// The context passed to the value by `dive`.
+pub enum DiveContext {
+    /// The context of the parent.
+    User,
+    /// `dive(context = <expr>)`
+    Expr(Expr),
+    /// `dive(as_ref)`, which projects the context of the parent through `AsRef`.
+    AsRef,
 }
 
 pub struct RawLength {
@@ -115,6 +227,11 @@ pub enum LengthMode {
     Utf16,
 }
 
+pub struct RawRange {
+    pub key: Option<Expr>,
+    pub range: Range<Expr>,
+}
+
 pub enum Either<L, R> {
     Left(L),
     Right(R),
@@ -138,11 +255,33 @@ pub enum Pattern {
     Expr(Expr),
 }
 
+pub struct RawPattern {
+    pub pattern: Pattern,
+    /// `max_len = <expr>`, the length in bytes above which the value is rejected without being matched.
+    pub max_len: Option<Expr>,
+}
+
+pub struct RawPatternCaptures {
+    pub pattern: Pattern,
+    pub check: Expr,
+}
+
 pub struct Str {
     pub span: Span,
     pub value: String,
 }
 
+impl quote::ToTokens for Str {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        syn::LitStr::new(&self.value, self.span).to_tokens(tokens)
+    }
+}
+
+pub struct StrList {
+    pub span: Span,
+    pub values: Vec<Str>,
+}
+
 pub struct Range<T> {
     pub span: Span,
     pub min: Option<T>,
@@ -158,7 +297,11 @@ pub struct Validate {
     pub ident: Ident,
     pub generics: Generics,
     pub context: (Type, Ident),
+    pub context_default: Option<Expr>,
+    pub custom_fields: Vec<Expr>,
     pub is_transparent: bool,
+    /// The type validated in place of `Self`, from `#[garde(remote = "...")]`.
+    pub remote: Option<Type>,
     pub kind: ValidateKind,
     // I don't know why Rust thinks this is unused.
     // It's both read and written, grep for `.allow_unvalidated`.
@@ -168,11 +311,17 @@ pub struct Validate {
 
 pub struct Options {
     pub allow_unvalidated: bool,
+    /// Set by `#[garde(skip_in_release)]`.
+    pub skip_in_release: bool,
+    /// Set by `#[garde(catch_unwind)]`.
+    pub catch_unwind: bool,
+    /// Set by `#[garde(validate_fields)]`.
+    pub validate_fields: bool,
 }
 
 pub enum ValidateKind {
     Struct(ValidateVariant),
-    Enum(Vec<(Ident, Option<ValidateVariant>)>),
+    Enum(Vec<(Ident, Option<ValidateVariant>, Vec<Expr>)>),
 }
 
 pub struct ValidateField {
@@ -180,11 +329,14 @@ pub struct ValidateField {
 
     pub adapter: Option<Path>,
     pub skip: Option<Span>,
-    pub alias: Option<String>,
+    pub rename: Option<String>,
+    pub aliases: Vec<String>,
     // pub message: Option<Message>,
     pub code: Option<String>,
 
-    pub dive: Option<Span>,
+    pub dive: Option<(Span, Dive)>,
+    /// `flatten`, which reports the errors of `dive` at the path of the parent instead of under this field.
+    pub flatten: Option<Span>,
     pub rule_set: RuleSet,
 }
 
@@ -199,17 +351,21 @@ impl ValidateField {
 }
 
 pub struct RuleSet {
-    pub rules: BTreeSet<ValidateRule>,
-    pub custom_rules: Vec<Expr>,
+    /// `dive` used in `inner` or `tuple`. At the top level of a field, this is stored in `ValidateField::dive`.
+    pub dive: Option<(Span, Dive)>,
+    /// Rules in the order they were written, each with its optional `when` guard.
+    pub rules: Vec<(Rule, Option<Expr>)>,
     pub inner: Option<Box<RuleSet>>,
+    pub tuple: Vec<(usize, RuleSet)>,
 }
 
 impl RuleSet {
     pub fn empty() -> Self {
         Self {
-            rules: BTreeSet::new(),
-            custom_rules: Vec::new(),
+            dive: None,
+            rules: Vec::new(),
             inner: None,
+            tuple: Vec::new(),
         }
     }
 
@@ -218,37 +374,58 @@ impl RuleSet {
             Some(inner) => inner.is_empty(),
             None => true,
         };
-        inner_empty && self.rules.is_empty() && self.custom_rules.is_empty()
+        inner_empty && self.dive.is_none() && self.rules.is_empty() && self.tuple.is_empty()
     }
 
     pub fn has_top_level_rules(&self) -> bool {
-        !self.rules.is_empty() || !self.custom_rules.is_empty()
+        self.dive.is_some() || !self.rules.is_empty() || !self.tuple.is_empty()
     }
 }
 
-#[repr(u8)]
+pub enum Rule {
+    Builtin(Box<ValidateRule>),
+    Custom(CustomRule),
+    CustomReport(Expr),
+}
+
 pub enum ValidateRule {
     Required,
     Ascii,
+    AsciiPrintable,
     Alphanumeric,
+    EachChar(Expr),
+    Charset(Charset),
     Email,
-    Url,
+    Url(UrlFlags),
     Ip,
     IpV4,
     IpV6,
+    Cidr,
+    CidrV4,
+    CidrV6,
     CreditCard,
     PhoneNumber,
+    SafePathComponent,
     LengthSimple(LengthRange),
     LengthBytes(LengthRange),
     LengthChars(LengthRange),
     LengthGraphemes(LengthRange),
     LengthUtf16(LengthRange),
+    ByteSize(ValidateRange<ByteSize>),
     Matches(Path),
-    Range(ValidateRange<Expr>),
-    Contains(Expr),
+    Range(ValidateRange<Expr>, Option<Expr>),
+    Contains(ValidateContains),
+    ContainsAny(Vec<Str>),
+    ContainsAll(Vec<Str>),
+    ContainsItem(Expr),
+    PrefixAny(Vec<Str>),
+    SuffixAny(Vec<Str>),
     Prefix(Expr),
     Suffix(Expr),
-    Pattern(ValidatePattern),
+    Glob(Expr),
+    Pattern(ValidatePattern, Option<Expr>),
+    NotPattern(ValidatePattern),
+    PatternCaptures(ValidatePattern, Expr),
 }
 
 type LengthRange = ValidateRange<Either<usize, Expr>>;
@@ -258,29 +435,50 @@ impl ValidateRule {
         match self {
             ValidateRule::Required => "required",
             ValidateRule::Ascii => "ascii",
+            ValidateRule::AsciiPrintable => "ascii::printable",
             ValidateRule::Alphanumeric => "alphanumeric",
+            ValidateRule::EachChar(_) => "each_char",
+            ValidateRule::Charset(_) => "charset",
             ValidateRule::Email => "email",
-            ValidateRule::Url => "url",
+            ValidateRule::Url(_) => "url",
             ValidateRule::Ip => "ip",
             ValidateRule::IpV4 => "ip",
             ValidateRule::IpV6 => "ip",
+            ValidateRule::Cidr => "cidr",
+            ValidateRule::CidrV4 => "cidr",
+            ValidateRule::CidrV6 => "cidr",
             ValidateRule::CreditCard => "credit_card",
             ValidateRule::PhoneNumber => "phone_number",
+            ValidateRule::SafePathComponent => "path",
             ValidateRule::LengthSimple(_) => "length::simple",
             ValidateRule::LengthBytes(_) => "length::bytes",
             ValidateRule::LengthChars(_) => "length::chars",
             ValidateRule::LengthGraphemes(_) => "length::graphemes",
             ValidateRule::LengthUtf16(_) => "length::utf16",
+            ValidateRule::ByteSize(_) => "byte_size",
             ValidateRule::Matches(_) => "matches",
-            ValidateRule::Range(_) => "range",
+            ValidateRule::Range(..) => "range",
             ValidateRule::Contains(_) => "contains",
+            ValidateRule::ContainsAny(_) => "contains_any",
+            ValidateRule::ContainsAll(_) => "contains_all",
+            ValidateRule::ContainsItem(_) => "contains_item",
+            ValidateRule::PrefixAny(_) => "prefix_any",
+            ValidateRule::SuffixAny(_) => "suffix_any",
             ValidateRule::Prefix(_) => "prefix",
             ValidateRule::Suffix(_) => "suffix",
-            ValidateRule::Pattern(_) => "pattern",
+            ValidateRule::Glob(_) => "glob",
+            ValidateRule::Pattern(..) => "pattern",
+            ValidateRule::NotPattern(_) => "not_pattern",
+            ValidateRule::PatternCaptures(..) => "pattern_captures",
         }
     }
 }
 
+pub enum ValidateContains {
+    Expr(Expr),
+    Char(syn::LitChar),
+}
+
 pub enum ValidatePattern {
     #[cfg(feature = "regex")]
     Lit(String),
@@ -307,32 +505,11 @@ impl ValidateVariant {
 
 impl PartialEq for ValidateRule {
     fn eq(&self, other: &Self) -> bool {
+        // `ValidateRule` is intentionally only compared by the discriminant,
+        // because we want there to only be one of each kind, without caring about
+        // the value.
         core::mem::discriminant(self) == core::mem::discriminant(other)
     }
 }
 
 impl Eq for ValidateRule {}
-
-impl ValidateRule {
-    fn discriminant(&self) -> u8 {
-        // SAFETY: Because `Self` is marked `repr(u8)`, its layout is a `repr(C)`
-        // `union` between `repr(C)` structs, each of which has the `u8`
-        // discriminant as its first field, so we can read the discriminant
-        // without offsetting the pointer.
-        unsafe { <*const _>::from(self).cast::<u8>().read() }
-    }
-}
-
-impl PartialOrd for ValidateRule {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-impl Ord for ValidateRule {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        // `ValidateRuleKind` is intentionally only compared by the discriminant,
-        // because we want there to only be one of each kind, without caring about
-        // the value.
-        self.discriminant().cmp(&other.discriminant())
-    }
-}
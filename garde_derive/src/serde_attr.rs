@@ -0,0 +1,132 @@
+//! Support for reading `#[serde(...)]` attributes, so that error keys match the serialized field names.
+//!
+//! This is only active with the `serde` feature. Any attribute which can't be understood here is
+//! ignored, because `serde` itself will report it.
+
+use syn::ext::IdentExt;
+use syn::{Attribute, Ident, LitStr, Token};
+
+/// Returns the name of a field after applying its `#[serde(rename)]`,
+/// or `rename_all` if it has no `rename` of its own.
+pub fn field_name(
+    ident: &Ident,
+    attrs: &[Attribute],
+    rename_all: Option<RenameRule>,
+) -> Option<String> {
+    if !cfg!(feature = "serde") {
+        return None;
+    }
+
+    find_name(attrs, "rename")
+        .or_else(|| rename_all.map(|rule| rule.apply(&ident.unraw().to_string())))
+}
+
+/// Returns the rule in a `#[serde(rename_all)]`-like attribute called `key`.
+pub fn rename_all(attrs: &[Attribute], key: &str) -> Option<RenameRule> {
+    if !cfg!(feature = "serde") {
+        return None;
+    }
+
+    find_name(attrs, key).and_then(|rule| RenameRule::parse(&rule))
+}
+
+/// Finds the value of `#[serde(key = "...")]` or `#[serde(key(deserialize = "..."))]`.
+///
+/// Validation happens on deserialized values, so the `deserialize` name is preferred.
+fn find_name(attrs: &[Attribute], key: &str) -> Option<String> {
+    let mut name = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(key) {
+                if meta.input.peek(Token![=]) {
+                    name = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else {
+                    meta.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("deserialize") {
+                            name = Some(meta.value()?.parse::<LitStr>()?.value());
+                        } else {
+                            skip(&meta)?;
+                        }
+                        Ok(())
+                    })?;
+                }
+            } else {
+                skip(&meta)?;
+            }
+            Ok(())
+        });
+    }
+    name
+}
+
+fn skip(meta: &syn::meta::ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        let _content;
+        syn::parenthesized!(_content in meta.input);
+    }
+    Ok(())
+}
+
+/// The casing conventions supported by `#[serde(rename_all)]`.
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn parse(rule: &str) -> Option<Self> {
+        Some(match rule {
+            "lowercase" => Self::Lower,
+            "UPPERCASE" => Self::Upper,
+            "PascalCase" => Self::Pascal,
+            "camelCase" => Self::Camel,
+            "snake_case" => Self::Snake,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "kebab-case" => Self::Kebab,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebab,
+            _ => return None,
+        })
+    }
+
+    /// Applies the rule to a `snake_case` field name, the same way `serde` does.
+    fn apply(self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_owned(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            Self::Camel => {
+                let pascal = Self::Pascal.apply(field);
+                let mut chars = pascal.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => pascal,
+                }
+            }
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+}
@@ -6,11 +6,11 @@ use syn::parse::Parse;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::As;
-use syn::{DeriveInput, Token, Type};
+use syn::{DeriveInput, Token, Type, TypeParamBound};
 
-use crate::model;
 use crate::model::List;
 use crate::util::{default_ctx_name, MaybeFoldError};
+use crate::{model, serde_attr};
 
 pub fn parse(input: DeriveInput) -> syn::Result<model::Input> {
     let mut error = None;
@@ -25,8 +25,8 @@ pub fn parse(input: DeriveInput) -> syn::Result<model::Input> {
         }
     };
     let kind = match &input.data {
-        syn::Data::Struct(v) => parse_struct(v),
-        syn::Data::Enum(v) => parse_enum(v),
+        syn::Data::Struct(v) => parse_struct(v, &input.attrs),
+        syn::Data::Enum(v) => parse_enum(v, &input.attrs),
         syn::Data::Union(v) => parse_union(v),
     };
     let kind = match kind {
@@ -91,25 +91,65 @@ impl Parse for model::Attr {
                 let content;
                 syn::parenthesized!(content in input);
                 let ty = content.parse::<Type>()?;
+                let bounds = if content.parse::<Token![:]>().is_ok() {
+                    Punctuated::<TypeParamBound, Token![+]>::parse_separated_nonempty(&content)?
+                        .into_iter()
+                        .collect()
+                } else {
+                    Vec::new()
+                };
                 let ident = if content.parse::<As>().is_ok() {
                     content.parse()?
                 } else {
                     default_ctx_name()
                 };
+                let default = if content.parse::<Token![,]>().is_ok() {
+                    let key = Ident::parse_any(&content)?;
+                    if key != "default" {
+                        return Err(syn::Error::new(key.span(), "unexpected argument"));
+                    }
+                    content.parse::<Token![=]>()?;
+                    Some(content.parse()?)
+                } else {
+                    None
+                };
 
-                Ok(model::Attr::Context(Box::new(ty), ident))
+                Ok(model::Attr::Context(Box::new(model::RawContext {
+                    ty,
+                    bounds,
+                    ident,
+                    default,
+                })))
+            }
+            "custom_fields" => {
+                let content;
+                syn::parenthesized!(content in input);
+                Ok(model::Attr::CustomFields(Box::new(content.parse()?)))
+            }
+            "validate" => {
+                input.parse::<Token![=]>()?;
+                Ok(model::Attr::Validate(Box::new(input.parse()?)))
             }
             "allow_unvalidated" => Ok(model::Attr::AllowUnvalidated),
             "transparent" => Ok(model::Attr::Transparent),
+            "skip_in_release" => Ok(model::Attr::SkipInRelease),
+            "catch_unwind" => Ok(model::Attr::CatchUnwind),
+            "validate_fields" => Ok(model::Attr::ValidateFields),
+            "remote" => {
+                input.parse::<Token![=]>()?;
+                let path = input.parse::<syn::LitStr>()?;
+                Ok(model::Attr::Remote(Box::new(path.parse()?)))
+            }
             _ => Err(syn::Error::new(ident.span(), "unrecognized attribute")),
         }
     }
 }
 
-fn parse_struct(node: &syn::DataStruct) -> syn::Result<model::InputKind> {
+fn parse_struct(node: &syn::DataStruct, attrs: &[syn::Attribute]) -> syn::Result<model::InputKind> {
     let mut error = None;
 
-    let fields = match parse_variant(&node.fields) {
+    let rename_all = serde_attr::rename_all(attrs, "rename_all");
+    let fields = match parse_variant(&node.fields, rename_all) {
         Ok(Some(v)) => v,
         Ok(None) => {
             error.maybe_fold(syn::Error::new(
@@ -131,13 +171,27 @@ fn parse_struct(node: &syn::DataStruct) -> syn::Result<model::InputKind> {
     Ok(model::InputKind::Struct(fields))
 }
 
-fn parse_enum(node: &syn::DataEnum) -> syn::Result<model::InputKind> {
+fn parse_enum(node: &syn::DataEnum, attrs: &[syn::Attribute]) -> syn::Result<model::InputKind> {
     let mut error = None;
     let mut variants = Vec::new();
 
+    let rename_all_fields = serde_attr::rename_all(attrs, "rename_all_fields");
+
     for variant in node.variants.iter() {
-        match parse_variant(&variant.fields) {
-            Ok(v) => variants.push((variant.ident.clone(), v)),
+        let hooks = match parse_variant_attrs(&variant.attrs) {
+            Ok(VariantAttrs { skip: true, .. }) => {
+                variants.push((variant.ident.clone(), None, Vec::new()));
+                continue;
+            }
+            Ok(VariantAttrs { hooks, .. }) => hooks,
+            Err(e) => {
+                error.maybe_fold(e);
+                continue;
+            }
+        };
+        let rename_all = serde_attr::rename_all(&variant.attrs, "rename_all").or(rename_all_fields);
+        match parse_variant(&variant.fields, rename_all) {
+            Ok(v) => variants.push((variant.ident.clone(), v, hooks)),
             Err(e) => error.maybe_fold(e),
         }
     }
@@ -149,6 +203,69 @@ fn parse_enum(node: &syn::DataEnum) -> syn::Result<model::InputKind> {
     Ok(model::InputKind::Enum(variants))
 }
 
+struct VariantAttrs {
+    skip: bool,
+    hooks: Vec<syn::Expr>,
+}
+
+/// The attributes which may be placed on an enum variant are `#[garde(skip)]`,
+/// which skips validation of the entire variant, and `#[garde(validate = <expr>)]`,
+/// which validates the whole value if it is that variant.
+fn parse_variant_attrs(attrs: &[syn::Attribute]) -> syn::Result<VariantAttrs> {
+    let mut error = None;
+    let mut skip = None;
+    let mut hooks = Vec::new();
+
+    for attr in attrs.iter() {
+        if attr.path().is_ident("garde") {
+            let result = attr.parse_args_with(|input: syn::parse::ParseStream| {
+                let ident = Ident::parse_any(input)?;
+                match ident.to_string().as_str() {
+                    "skip" => {
+                        if skip.is_some() {
+                            return Err(syn::Error::new(
+                                ident.span(),
+                                "duplicate attribute `skip`",
+                            ));
+                        }
+                        skip = Some(ident.span());
+                    }
+                    "validate" => {
+                        input.parse::<Token![=]>()?;
+                        hooks.push(input.parse()?);
+                    }
+                    _ => {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            "unrecognized variant attribute",
+                        ))
+                    }
+                }
+                Ok(())
+            });
+            if let Err(e) = result {
+                error.maybe_fold(e);
+            }
+        }
+    }
+
+    if let (Some(span), false) = (skip, hooks.is_empty()) {
+        error.maybe_fold(syn::Error::new(
+            span,
+            "`skip` may not be combined with `validate`",
+        ));
+    }
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    Ok(VariantAttrs {
+        skip: skip.is_some(),
+        hooks,
+    })
+}
+
 fn parse_union(node: &syn::DataUnion) -> syn::Result<model::InputKind> {
     Err(syn::Error::new(
         node.union_token.span(),
@@ -156,7 +273,10 @@ fn parse_union(node: &syn::DataUnion) -> syn::Result<model::InputKind> {
     ))
 }
 
-fn parse_variant(fields: &syn::Fields) -> syn::Result<Option<model::Variant>> {
+fn parse_variant(
+    fields: &syn::Fields,
+    rename_all: Option<serde_attr::RenameRule>,
+) -> syn::Result<Option<model::Variant>> {
     let mut error = None;
 
     let variant = match fields {
@@ -172,7 +292,15 @@ fn parse_variant(fields: &syn::Fields) -> syn::Result<Option<model::Variant>> {
                         Vec::new()
                     }
                 };
-                fields.insert(ident, model::Field { ty, rules });
+                let serde_name = serde_attr::field_name(&ident, &field.attrs, rename_all);
+                fields.insert(
+                    ident,
+                    model::Field {
+                        ty,
+                        rules,
+                        serde_name,
+                    },
+                );
             }
             Some(model::Variant::Struct(fields))
         }
@@ -187,7 +315,11 @@ fn parse_variant(fields: &syn::Fields) -> syn::Result<Option<model::Variant>> {
                         Vec::new()
                     }
                 };
-                fields.push(model::Field { ty, rules });
+                fields.push(model::Field {
+                    ty,
+                    rules,
+                    serde_name: None,
+                });
             }
             Some(model::Variant::Tuple(fields))
         }
@@ -209,12 +341,29 @@ fn parse_field_attr_list(attrs: &[syn::Attribute]) -> syn::Result<Vec<model::Raw
         if attr.path().is_ident("garde") {
             match attr.parse_args_with(Punctuated::<_, syn::token::Comma>::parse_terminated) {
                 Ok(list) => {
+                    let mut when = None;
+                    let mut attr_rules = Vec::new();
                     for rule in list {
                         match rule {
-                            ContinueOnFail::Ok(v) => rules.push(v),
+                            ContinueOnFail::Ok(model::RawRule {
+                                span,
+                                kind: model::RawRuleKind::When(expr),
+                                ..
+                            }) => match when {
+                                Some(_) => {
+                                    error.maybe_fold(syn::Error::new(span, "duplicate `when`"))
+                                }
+                                None => when = Some(expr),
+                            },
+                            ContinueOnFail::Ok(v) => attr_rules.push(v),
                             ContinueOnFail::Err(e) => error.maybe_fold(e),
                         }
                     }
+                    // `when` applies to every rule in the same attribute.
+                    for mut rule in attr_rules {
+                        rule.when.clone_from(&when);
+                        rules.push(rule);
+                    }
                 }
                 Err(e) => error.maybe_fold(e),
             }
@@ -249,9 +398,20 @@ impl Parse for model::RawRule {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let ident = Ident::parse_any(input)?;
 
+        if ident == "when" {
+            <Token![=]>::parse(input)?;
+            return Ok(model::RawRule {
+                span: ident.span(),
+                kind: model::RawRuleKind::When(input.parse()?),
+                when: None,
+            });
+        }
+
+        // Rules written as `$rule[$input]` have optional arguments,
+        // which they parse directly from the input.
         macro_rules! rules {
             (($input:ident, $ident:ident) {
-                $($name:literal => $rule:ident $(($content:ident))?,)*
+                $($name:literal => $rule:ident $(($content:ident))? $([$optional:ident])?,)*
             }) => {
                 match $ident.to_string().as_str() {
                     $(
@@ -262,7 +422,8 @@ impl Parse for model::RawRule {
                             )?
                             Ok(model::RawRule {
                                 span: $ident.span(),
-                                kind: model::RawRuleKind::$rule $(($content.parse()?))?
+                                kind: model::RawRuleKind::$rule $(($content.parse()?))? $(($optional.parse()?))?,
+                                when: None,
                             })
                         }
                     )*
@@ -276,30 +437,220 @@ impl Parse for model::RawRule {
                 "skip" => Skip,
                 "adapt" => Adapt(content),
                 "rename" => Rename(content),
+                "alias" => Alias(content),
                 // "message" => Message(content),
                 "code" => Code(content),
-                "dive" => Dive,
+                "dive" => Dive[input],
+                "dive_deref" => DiveDeref[input],
+                "flatten" => Flatten,
                 "required" => Required,
-                "ascii" => Ascii,
+                "ascii" => Ascii[input],
                 "alphanumeric" => Alphanumeric,
+                "each_char" => EachChar(content),
+                "charset" => Charset(content),
                 "email" => Email,
-                "url" => Url,
+                "url" => Url[input],
                 "ip" => Ip,
                 "ipv4" => IpV4,
                 "ipv6" => IpV6,
+                "cidr" => Cidr,
+                "cidrv4" => CidrV4,
+                "cidrv6" => CidrV6,
                 "credit_card" => CreditCard,
                 "phone_number" => PhoneNumber,
+                "path_component" => SafePathComponent,
                 "length" => Length(content),
+                "byte_size" => ByteSize(content),
                 "matches" => Matches(content),
                 "range" => Range(content),
                 "contains" => Contains(content),
+                "contains_any" => ContainsAny(content),
+                "contains_all" => ContainsAll(content),
+                "contains_item" => ContainsItem(content),
+                "prefix_any" => PrefixAny(content),
+                "suffix_any" => SuffixAny(content),
                 "prefix" => Prefix(content),
                 "suffix" => Suffix(content),
+                "glob" => Glob(content),
                 "pattern" => Pattern(content),
+                "not_pattern" => NotPattern(content),
+                "pattern_captures" => PatternCaptures(content),
                 "custom" => Custom(content),
+                "custom_report" => CustomReport(content),
                 "inner" => Inner(content),
+                "tuple" => Tuple(content),
+            }
+        }
+    }
+}
+
+impl Parse for model::RawAscii {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if !input.peek(syn::token::Paren) {
+            return Ok(model::RawAscii { printable: false });
+        }
+
+        let content;
+        syn::parenthesized!(content in input);
+        let ident = Ident::parse_any(&content)?;
+        if ident != "printable" {
+            return Err(syn::Error::new(ident.span(), "unexpected argument"));
+        }
+        if !content.is_empty() {
+            return Err(content.error("unexpected input"));
+        }
+
+        Ok(model::RawAscii { printable: true })
+    }
+}
+
+impl Parse for model::Charset {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident = Ident::parse_any(input)?;
+        let charset = if ident == "ascii" {
+            model::Charset::Ascii
+        } else if ident == "latin1" {
+            model::Charset::Latin1
+        } else if ident == "max" {
+            <Token![=]>::parse(input)?;
+            model::Charset::Max(input.parse()?)
+        } else {
+            return Err(syn::Error::new(
+                ident.span(),
+                "expected one of `ascii`, `latin1` or `max = <expr>`",
+            ));
+        };
+        if !input.is_empty() {
+            return Err(input.error("unexpected input"));
+        }
+
+        Ok(charset)
+    }
+}
+
+impl Parse for model::UrlFlags {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut flags = model::UrlFlags {
+            require_host: false,
+            https: false,
+        };
+        if !input.peek(syn::token::Paren) {
+            return Ok(flags);
+        }
+
+        let content;
+        syn::parenthesized!(content in input);
+        let args =
+            Punctuated::<Ident, Token![,]>::parse_terminated_with(&content, Ident::parse_any)?;
+
+        let mut error = None;
+        for ident in args {
+            let flag = if ident == "require_host" {
+                &mut flags.require_host
+            } else if ident == "https" {
+                &mut flags.https
+            } else {
+                error.maybe_fold(syn::Error::new(ident.span(), "unexpected argument"));
+                continue;
+            };
+            if *flag {
+                error.maybe_fold(syn::Error::new(ident.span(), "duplicate argument"));
             }
+            *flag = true;
+        }
+
+        if let Some(error) = error {
+            return Err(error);
         }
+
+        Ok(flags)
+    }
+}
+
+impl Parse for model::RawTupleElement {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let index = <syn::LitInt as Parse>::parse(input)?;
+        let span = index.span();
+        let content;
+        syn::parenthesized!(content in input);
+        Ok(model::RawTupleElement {
+            span,
+            index: index.base10_parse()?,
+            rules: content.parse()?,
+        })
+    }
+}
+
+impl Parse for model::CustomRule {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let expr = input.parse()?;
+        let mut fatal = false;
+        if input.peek(Token![,]) {
+            <Token![,]>::parse(input)?;
+            if !input.is_empty() {
+                let ident = Ident::parse_any(input)?;
+                if ident != "fatal" {
+                    return Err(syn::Error::new(ident.span(), "unexpected argument"));
+                }
+                fatal = true;
+                if input.peek(Token![,]) {
+                    <Token![,]>::parse(input)?;
+                }
+            }
+        }
+        if !input.is_empty() {
+            return Err(input.error("unexpected input"));
+        }
+
+        Ok(model::CustomRule { expr, fatal })
+    }
+}
+
+impl Parse for model::RawDive {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if !input.peek(syn::token::Paren) {
+            return Ok(model::RawDive {
+                context: model::DiveContext::User,
+            });
+        }
+
+        let content;
+        syn::parenthesized!(content in input);
+        let args = Punctuated::<syn::Meta, Token![,]>::parse_terminated(&content)?;
+
+        let mut error = None;
+        let mut context = model::DiveContext::User;
+
+        for arg in args {
+            let path = arg.path();
+            let span = path.span();
+            if !matches!(context, model::DiveContext::User) {
+                if path.is_ident("context") || path.is_ident("as_ref") {
+                    error.maybe_fold(syn::Error::new(
+                        span,
+                        "only one of `context` and `as_ref` may be used",
+                    ));
+                } else {
+                    error.maybe_fold(syn::Error::new(span, "unexpected argument"));
+                }
+                continue;
+            }
+            match arg {
+                syn::Meta::NameValue(pair) if pair.path.is_ident("context") => {
+                    context = model::DiveContext::Expr(pair.value);
+                }
+                syn::Meta::Path(path) if path.is_ident("as_ref") => {
+                    context = model::DiveContext::AsRef;
+                }
+                _ => error.maybe_fold(syn::Error::new(span, "unexpected argument")),
+            }
+        }
+
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        Ok(model::RawDive { context })
     }
 }
 
@@ -313,6 +664,58 @@ impl Parse for model::Pattern {
     }
 }
 
+impl Parse for model::RawPattern {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let pattern = model::Pattern::parse(input)?;
+        let mut max_len = None;
+        if !input.is_empty() {
+            <Token![,]>::parse(input)?;
+        }
+        if !input.is_empty() {
+            let arg = syn::MetaNameValue::parse(input)?;
+            if !arg.path.is_ident("max_len") {
+                return Err(syn::Error::new(arg.path.span(), "unexpected argument"));
+            }
+            max_len = Some(arg.value);
+            if !input.is_empty() {
+                <Token![,]>::parse(input)?;
+            }
+        }
+        Ok(model::RawPattern { pattern, max_len })
+    }
+}
+
+impl Parse for model::RawPatternCaptures {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let pattern = model::Pattern::parse(input)?;
+        <Token![,]>::parse(input)?;
+        let check = syn::Expr::parse(input)?;
+        Ok(model::RawPatternCaptures { pattern, check })
+    }
+}
+
+impl Parse for model::StrList {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+        let array = <syn::ExprArray as Parse>::parse(input)?;
+
+        let mut error = None;
+        let mut values = Vec::new();
+        for elem in array.elems {
+            match <model::Str as FromExpr>::from_expr(elem) {
+                Ok(v) => values.push(v),
+                Err(e) => error.maybe_fold(e),
+            }
+        }
+
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        Ok(model::StrList { span, values })
+    }
+}
+
 impl Parse for model::Str {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         Ok(model::Str {
@@ -358,6 +761,8 @@ impl Parse for model::RawLength {
                 RawLengthArgument::Min(span, v) => {
                     if min.is_some() {
                         error.maybe_fold(syn::Error::new(span, "duplicate argument"))
+                    } else if equal.is_some() {
+                        error.maybe_fold(syn::Error::new(span, EQUAL_CONFLICT))
                     } else {
                         min = Some(v)
                     }
@@ -365,6 +770,8 @@ impl Parse for model::RawLength {
                 RawLengthArgument::Max(span, v) => {
                     if max.is_some() {
                         error.maybe_fold(syn::Error::new(span, "duplicate argument"))
+                    } else if equal.is_some() {
+                        error.maybe_fold(syn::Error::new(span, EQUAL_CONFLICT))
                     } else {
                         max = Some(v)
                     }
@@ -372,6 +779,8 @@ impl Parse for model::RawLength {
                 RawLengthArgument::Equal(span, v) => {
                     if equal.is_some() {
                         error.maybe_fold(syn::Error::new(span, "duplicate argument"))
+                    } else if min.is_some() || max.is_some() {
+                        error.maybe_fold(syn::Error::new(span, EQUAL_CONFLICT))
                     } else {
                         equal = Some(v)
                     }
@@ -402,6 +811,8 @@ impl Parse for model::RawLength {
     }
 }
 
+const EQUAL_CONFLICT: &str = "no `min` or `max` allowed if using `equal`";
+
 enum RawLengthArgument {
     Min(Span, model::Either<usize, syn::Expr>),
     Max(Span, model::Either<usize, syn::Expr>),
@@ -448,6 +859,47 @@ impl Parse for RawLengthArgument {
     }
 }
 
+impl Parse for model::RawRange {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+
+        let pairs = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut key = None;
+        let mut rest = Vec::new();
+        for pair in pairs {
+            if pair.path.is_ident("key") {
+                if key.is_some() {
+                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                    continue;
+                }
+                key = Some(pair.value);
+            } else {
+                rest.push(pair);
+            }
+        }
+
+        let range = match parse_range_pairs(span, rest) {
+            Ok(range) => Some(range),
+            Err(e) => {
+                error.maybe_fold(e);
+                None
+            }
+        };
+
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        Ok(model::RawRange {
+            key,
+            range: range.expect("range is only missing on error"),
+        })
+    }
+}
+
 impl<T> Parse for model::Range<T>
 where
     T: FromExpr,
@@ -457,76 +909,89 @@ where
 
         let pairs = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
 
-        let mut error = None;
+        parse_range_pairs(span, pairs)
+    }
+}
 
-        let mut min = None::<T>;
-        let mut max = None::<T>;
-        let mut equal = None::<T>;
+fn parse_range_pairs<T>(
+    span: Span,
+    pairs: impl IntoIterator<Item = syn::MetaNameValue>,
+) -> syn::Result<model::Range<T>>
+where
+    T: FromExpr,
+{
+    let mut error = None;
 
-        for pair in pairs {
-            if pair.path.is_ident("min") {
-                if min.is_some() {
-                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+    let mut min = None::<T>;
+    let mut max = None::<T>;
+    let mut equal = None::<T>;
+
+    for pair in pairs {
+        if pair.path.is_ident("min") {
+            if min.is_some() {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                continue;
+            }
+            let value = match <T as FromExpr>::from_expr(pair.value) {
+                Ok(v) => v,
+                Err(e) => {
+                    error.maybe_fold(e);
                     continue;
                 }
-                let value = match <T as FromExpr>::from_expr(pair.value) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error.maybe_fold(e);
-                        continue;
-                    }
-                };
-                min = Some(value);
-            } else if pair.path.is_ident("max") {
-                if max.is_some() {
-                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+            };
+            if equal.is_some() {
+                error.maybe_fold(syn::Error::new(pair.path.span(), EQUAL_CONFLICT));
+            }
+            min = Some(value);
+        } else if pair.path.is_ident("max") {
+            if max.is_some() {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                continue;
+            }
+            let value = match <T as FromExpr>::from_expr(pair.value) {
+                Ok(v) => v,
+                Err(e) => {
+                    error.maybe_fold(e);
                     continue;
                 }
-                let value = match <T as FromExpr>::from_expr(pair.value) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error.maybe_fold(e);
-                        continue;
-                    }
-                };
-                max = Some(value);
-            } else if pair.path.is_ident("equal") {
-                if equal.is_some() {
-                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+            };
+            if equal.is_some() {
+                error.maybe_fold(syn::Error::new(pair.path.span(), EQUAL_CONFLICT));
+            }
+            max = Some(value);
+        } else if pair.path.is_ident("equal") {
+            if equal.is_some() {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                continue;
+            }
+            let value = match <T as FromExpr>::from_expr(pair.value) {
+                Ok(v) => v,
+                Err(e) => {
+                    error.maybe_fold(e);
                     continue;
                 }
-                let value = match <T as FromExpr>::from_expr(pair.value) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error.maybe_fold(e);
-                        continue;
-                    }
-                };
+            };
 
-                if min.is_some() || max.is_some() {
-                    error.maybe_fold(syn::Error::new(
-                        pair.path.span(),
-                        "min or max conflict with equal",
-                    ));
-                }
-                equal = Some(value);
-            } else {
-                error.maybe_fold(syn::Error::new(pair.path.span(), "unexpected argument"));
-                continue;
+            if min.is_some() || max.is_some() {
+                error.maybe_fold(syn::Error::new(pair.path.span(), EQUAL_CONFLICT));
             }
-        }
-
-        if let Some(error) = error {
-            Err(error)
+            equal = Some(value);
         } else {
-            Ok(model::Range {
-                span,
-                min,
-                max,
-                equal,
-            })
+            error.maybe_fold(syn::Error::new(pair.path.span(), "unexpected argument"));
+            continue;
         }
     }
+
+    if let Some(error) = error {
+        Err(error)
+    } else {
+        Ok(model::Range {
+            span,
+            min,
+            max,
+            equal,
+        })
+    }
 }
 
 impl<T: Parse> Parse for List<T> {
@@ -562,6 +1027,21 @@ impl FromExpr for syn::Expr {
     }
 }
 
+impl FromExpr for model::Str {
+    fn from_expr(v: syn::Expr) -> syn::Result<Self> {
+        match v {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(v),
+                ..
+            }) => Ok(model::Str {
+                span: v.span(),
+                value: v.value(),
+            }),
+            _ => Err(syn::Error::new(v.span(), "expected string literal")),
+        }
+    }
+}
+
 impl FromExpr for usize {
     fn from_expr(v: syn::Expr) -> syn::Result<Self> {
         match v {
@@ -13,6 +13,18 @@ impl Check {
         cargo("clippy")
             .with_args(["--all-features", "--", "-D", "warnings"])
             .run()?;
+        cargo("clippy")
+            .with_args([
+                "--package",
+                "garde",
+                "--no-default-features",
+                "--features",
+                "derive,serde,unicode",
+                "--",
+                "-D",
+                "warnings",
+            ])
+            .run()?;
         cargo("deny").with_args(["--all-features", "check"]).run()?;
         cargo("udeps").with_arg("--all-features").run()?;
         cargo("pants").with_arg("--dev").run()?;